@@ -0,0 +1,189 @@
+"""
+Compare two exported workbooks ("what changed since the last backup?").
+
+Backups are the Excel files written by ``/api/save``: one sheet per account
+with Tanggal, Uraian, Penerimaan_* and Pengeluaran_* columns. Transactions
+have no stable id in the workbook, so they are matched on their content.
+"""
+
+import io
+from collections import Counter
+from datetime import date, datetime
+from typing import Any, Dict, List, Optional, Tuple
+
+from openpyxl import load_workbook
+
+from balances import net_amount
+
+# Month names written by the Excel export (Indonesian locale)
+INDONESIAN_MONTHS = {
+    "januari": 1, "februari": 2, "maret": 3, "april": 4, "mei": 5, "juni": 6,
+    "juli": 7, "agustus": 8, "september": 9, "oktober": 10, "november": 11, "desember": 12,
+}
+
+
+def parse_backup_date(value: Any) -> str:
+    """Normalise a Tanggal cell to YYYY-MM-DD, leaving unknown formats as text."""
+    if isinstance(value, datetime):
+        return value.date().isoformat()
+    if isinstance(value, date):
+        return value.isoformat()
+    text = str(value or "").strip()
+    parts = text.split()
+    if len(parts) == 3 and parts[1].lower() in INDONESIAN_MONTHS:
+        try:
+            return date(int(parts[2]), INDONESIAN_MONTHS[parts[1].lower()], int(parts[0])).isoformat()
+        except ValueError:
+            return text
+    try:
+        return datetime.strptime(text[:10], "%Y-%m-%d").date().isoformat()
+    except ValueError:
+        return text
+
+
+def read_backup(content: bytes) -> Dict[str, List[Dict[str, Any]]]:
+    """
+    Read transactions per account from an exported workbook.
+
+    Sheets without a Tanggal column (e.g. the placeholder sheet written for an
+    account without transactions) count as accounts with no transactions.
+
+    Raises:
+        ValueError: If the content is not a readable Excel workbook
+    """
+    try:
+        wb = load_workbook(io.BytesIO(content), read_only=True, data_only=True)
+    except Exception as e:
+        raise ValueError(f"Not a readable Excel workbook: {e}")
+
+    accounts: Dict[str, List[Dict[str, Any]]] = {}
+    for ws in wb.worksheets:
+        rows = ws.iter_rows(values_only=True)
+        headers = [str(h or "").strip() for h in next(rows, [])]
+        lower = [h.lower() for h in headers]
+        accounts[ws.title] = []
+        if "tanggal" not in lower:
+            continue
+
+        for row in rows:
+            values = dict(zip(lower, row))
+            if all(v in (None, "") for v in row):
+                continue
+            tx: Dict[str, Any] = {
+                "tanggal": parse_backup_date(values.get("tanggal")),
+                "uraian": str(values.get("uraian") or "").strip(),
+                "penerimaan": {},
+                "pengeluaran": {},
+            }
+            for header, value in zip(headers, row):
+                prefix, _, category = header.partition("_")
+                entry_type = prefix.lower()
+                if category and entry_type in ("penerimaan", "pengeluaran") and value:
+                    try:
+                        tx[entry_type][category] = float(value)
+                    except (TypeError, ValueError):
+                        continue
+            accounts[ws.title].append(tx)
+    wb.close()
+    return accounts
+
+
+def _key(tx: Dict[str, Any]) -> Tuple:
+    return (
+        tx["tanggal"],
+        tx["uraian"],
+        tuple(sorted(tx["penerimaan"].items())),
+        tuple(sorted(tx["pengeluaran"].items())),
+    )
+
+
+def _balance(transactions: List[Dict[str, Any]]) -> float:
+    return sum((net_amount(tx["penerimaan"], tx["pengeluaran"]) for tx in transactions), 0.0)
+
+
+def _unmatched(transactions: List[Dict[str, Any]], other: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+    """Transactions with no identical counterpart in ``other`` (duplicates count separately)."""
+    available = Counter(_key(tx) for tx in other)
+    unmatched = []
+    for tx in transactions:
+        if available[_key(tx)]:
+            available[_key(tx)] -= 1
+        else:
+            unmatched.append(tx)
+    return unmatched
+
+
+def _compare_account(
+    name: str, before: List[Dict[str, Any]], after: List[Dict[str, Any]]
+) -> Optional[Dict[str, Any]]:
+    """Differences for one account present in both backups, or None if unchanged."""
+    removed = _unmatched(before, after)
+    added = _unmatched(after, before)
+
+    # A removed and an added entry with the same date and description is an edit
+    changed = []
+    for old in list(removed):
+        match = next(
+            (new for new in added if (new["tanggal"], new["uraian"]) == (old["tanggal"], old["uraian"])),
+            None,
+        )
+        if match is not None:
+            removed.remove(old)
+            added.remove(match)
+            changed.append({"before": old, "after": match})
+
+    balance_a, balance_b = _balance(before), _balance(after)
+    if not (added or removed or changed) and balance_a == balance_b:
+        return None
+    return {
+        "name": name,
+        "balance_a": balance_a,
+        "balance_b": balance_b,
+        "balance_delta": balance_b - balance_a,
+        "added": added,
+        "removed": removed,
+        "changed": changed,
+    }
+
+
+def compare_backups(
+    backup_a: Dict[str, List[Dict[str, Any]]], backup_b: Dict[str, List[Dict[str, Any]]]
+) -> Dict[str, Any]:
+    """
+    Report what changed from backup A (older) to backup B (newer).
+
+    Returns:
+        Dict with accounts only in A or B (with their balance), the changed
+        accounts with added, removed and edited transactions, and counts
+        over all accounts, including the ones only in A or B
+    """
+    names_a, names_b = set(backup_a), set(backup_b)
+    accounts_added = [
+        {"name": name, "balance": _balance(backup_b[name]), "transactions": len(backup_b[name])}
+        for name in sorted(names_b - names_a)
+    ]
+    accounts_removed = [
+        {"name": name, "balance": _balance(backup_a[name]), "transactions": len(backup_a[name])}
+        for name in sorted(names_a - names_b)
+    ]
+    changed_accounts = []
+    for name in sorted(names_a & names_b):
+        diff = _compare_account(name, backup_a[name], backup_b[name])
+        if diff is not None:
+            changed_accounts.append(diff)
+
+    return {
+        "accounts_added": accounts_added,
+        "accounts_removed": accounts_removed,
+        "accounts_changed": changed_accounts,
+        "summary": {
+            # Transactions of wholly added or removed accounts count as added or removed
+            "transactions_added": sum(len(diff["added"]) for diff in changed_accounts)
+            + sum(account["transactions"] for account in accounts_added),
+            "transactions_removed": sum(len(diff["removed"]) for diff in changed_accounts)
+            + sum(account["transactions"] for account in accounts_removed),
+            "transactions_changed": sum(len(diff["changed"]) for diff in changed_accounts),
+            "balance_a": sum(_balance(txs) for txs in backup_a.values()),
+            "balance_b": sum(_balance(txs) for txs in backup_b.values()),
+        },
+    }
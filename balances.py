@@ -0,0 +1,56 @@
+"""
+Sign conventions for transaction amounts.
+
+Penerimaan (income) increases an account balance and Pengeluaran (expense)
+decreases it. Balance and report calculations should go through these helpers
+so the rule is defined in one place.
+"""
+
+from typing import Dict, Mapping, Optional
+
+PENERIMAAN = "penerimaan"
+PENGELUARAN = "pengeluaran"
+
+# Direction in which each entry type moves the balance
+NORMAL_SIGN: Dict[str, int] = {
+    PENERIMAAN: 1,
+    PENGELUARAN: -1,
+}
+
+# How exports show amounts: separate penerimaan/pengeluaran columns, or one
+# signed column using presentation_amount. "split" is the bookkeeper layout.
+SPLIT = "split"
+SIGNED = "signed"
+PRESENTATIONS = (SPLIT, SIGNED)
+
+
+def sum_categories(amounts: Optional[Mapping[str, float]]) -> float:
+    """Sum all category amounts of one entry type, treating missing values as 0."""
+    return float(sum(float(value or 0) for value in (amounts or {}).values()))
+
+
+def net_from_totals(income: float, expense: float) -> float:
+    """Net effect of income and expense totals on the balance (e.g. for a period)."""
+    return NORMAL_SIGN[PENERIMAAN] * float(income or 0) + NORMAL_SIGN[PENGELUARAN] * float(
+        expense or 0
+    )
+
+
+def net_amount(
+    penerimaan: Optional[Mapping[str, float]],
+    pengeluaran: Optional[Mapping[str, float]],
+) -> float:
+    """Net effect of a transaction on the account balance."""
+    return net_from_totals(sum_categories(penerimaan), sum_categories(pengeluaran))
+
+
+def presentation_amount(entry_type: str, amount: float) -> float:
+    """
+    Signed amount for display in reports.
+
+    The sign is the amount's effect on the balance, as in ``net_amount``, so
+    the signed lines of a transaction add up to its net. Income entered as a
+    positive number shows as positive and expenses as negative; a negative
+    entry (e.g. a refund) flips its sign.
+    """
+    return NORMAL_SIGN[entry_type] * float(amount or 0)
@@ -0,0 +1,96 @@
+"""
+Pre-aggregated chart series, sized for plotting.
+
+Every series is derived from ``reports.build_report`` data, so the charts
+match the report tables for the same parameters.
+"""
+
+from typing import Any, Callable, Dict, List
+
+# Largest number of points returned for the balance line
+MAX_BALANCE_POINTS = 366
+
+# Slices shown in a mix chart before the rest is grouped as "Lainnya"
+MIX_SLICES = 6
+
+
+def _period_series(rows: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+    return [
+        {
+            "period": row["period"],
+            "label": row["label"],
+            "income": row["income"],
+            "expense": row["expense"],
+            "net": row["net"],
+        }
+        for row in rows
+    ]
+
+
+def monthly_income_expense(report: Dict[str, Any]) -> List[Dict[str, Any]]:
+    """Income, expense and net per month."""
+    return _period_series(report["monthly"])
+
+
+def yearly_income_expense(report: Dict[str, Any]) -> List[Dict[str, Any]]:
+    """Income, expense and net per year."""
+    return _period_series(report["yearly"])
+
+
+def balance_over_time(report: Dict[str, Any]) -> List[Dict[str, Any]]:
+    """
+    End-of-day balance for each day with transactions.
+
+    Long histories are thinned to ``MAX_BALANCE_POINTS`` evenly spaced days,
+    always keeping the last one so the line ends at the closing balance.
+    """
+    by_date: Dict[str, float] = {}
+    if report["start_date"]:
+        by_date[report["start_date"]] = report["opening_balance"]
+    for row in report["running"]:
+        by_date[row["date"]] = row["running_balance"]
+    points = [{"date": day, "balance": balance} for day, balance in sorted(by_date.items())]
+
+    if len(points) > MAX_BALANCE_POINTS:
+        step = len(points) / MAX_BALANCE_POINTS
+        thinned = [points[int(i * step)] for i in range(MAX_BALANCE_POINTS - 1)]
+        points = thinned + [points[-1]]
+    return points
+
+
+def _mix(categories: Dict[str, float]) -> List[Dict[str, Any]]:
+    """Largest categories first, with the remainder grouped as "Lainnya"."""
+    ranked = sorted(categories.items(), key=lambda item: item[1], reverse=True)
+    total = sum(categories.values())
+    slices = [{"name": name, "value": value} for name, value in ranked[:MIX_SLICES]]
+    rest = sum(value for _, value in ranked[MIX_SLICES:])
+    if rest:
+        slices.append({"name": "Lainnya", "value": rest})
+    for item in slices:
+        item["share_percent"] = item["value"] / total * 100 if total else 0.0
+    return slices
+
+
+def income_mix(report: Dict[str, Any]) -> List[Dict[str, Any]]:
+    """Income per category, largest first (the books have no customers)."""
+    return _mix(report["income_by_category"])
+
+
+def expense_mix(report: Dict[str, Any]) -> List[Dict[str, Any]]:
+    """Expense per category, largest first."""
+    return _mix(report["expense_by_category"])
+
+
+# Chart types accepted by the chart endpoint
+CHARTS: Dict[str, Callable[[Dict[str, Any]], List[Dict[str, Any]]]] = {
+    "monthly": monthly_income_expense,
+    "yearly": yearly_income_expense,
+    "balance": balance_over_time,
+    "income-mix": income_mix,
+    "expense-mix": expense_mix,
+}
+
+
+def all_series(report: Dict[str, Any]) -> Dict[str, List[Dict[str, Any]]]:
+    """Every chart series of one report, keyed by chart type."""
+    return {chart: build(report) for chart, build in CHARTS.items()}
@@ -0,0 +1,286 @@
+"""
+CSV/zip export bundles built from posted account data.
+
+These are meant for handing a selection of transactions to someone outside
+the app (e.g. an auditor), so every file is plain CSV that opens directly in
+Excel.
+"""
+
+import csv
+import io
+import zipfile
+from datetime import datetime
+from typing import Any, Dict, Iterable, List, Optional, Sequence, Tuple
+
+from balances import (
+    PENERIMAAN,
+    PENGELUARAN,
+    SIGNED,
+    SPLIT,
+    net_from_totals,
+    presentation_amount,
+    sum_categories,
+)
+from reports import (
+    build_report,
+    dated_transactions,
+    parse_filter_date,
+    period_range,
+    round_amount,
+    round_rows,
+    select_accounts,
+)
+
+JOURNAL_HEADERS = ["Tanggal", "Akun", "Uraian", "Jenis", "Kategori", "Penerimaan", "Pengeluaran"]
+SIGNED_JOURNAL_HEADERS = ["Tanggal", "Akun", "Uraian", "Jenis", "Kategori", "Jumlah"]
+
+
+def journal_headers(presentation: str = SPLIT) -> List[str]:
+    """Journal columns for the split or signed presentation."""
+    return SIGNED_JOURNAL_HEADERS if presentation == SIGNED else JOURNAL_HEADERS
+
+
+def _matches_search(tx: Any, query: str) -> bool:
+    """Same matching as the editor search: description or category name."""
+    query = query.lower()
+    names = list((tx.penerimaan or {}).keys()) + list((tx.pengeluaran or {}).keys())
+    return query in (tx.uraian or "").lower() or any(query in name.lower() for name in names)
+
+
+def filter_transactions(
+    accounts: Iterable[Any],
+    account_name: Optional[str] = None,
+    start_date: Optional[str] = None,
+    end_date: Optional[str] = None,
+    entry_type: Optional[str] = None,
+    search: Optional[str] = None,
+) -> List[Tuple[Any, Any, Any]]:
+    """
+    Select (date, account, transaction) tuples matching the editor filters.
+
+    Args:
+        accounts: Account objects with ``name`` and ``transactions``
+        account_name: Limit to this account
+        start_date: Inclusive start date (YYYY-MM-DD)
+        end_date: Inclusive end date (YYYY-MM-DD)
+        entry_type: Only transactions with penerimaan or pengeluaran amounts
+        search: Text to find in the description or a category name
+
+    Raises:
+        KeyError: If ``account_name`` is given but no account has that name
+        ValueError: If ``start_date`` or ``end_date`` is not a valid date
+    """
+    start = parse_filter_date(start_date, "start date")
+    end = parse_filter_date(end_date, "end date")
+    selected = []
+    for tx_date, account, tx in dated_transactions(select_accounts(accounts, account_name)):
+        if start and tx_date < start:
+            continue
+        if end and tx_date > end:
+            continue
+        if entry_type and not getattr(tx, entry_type):
+            continue
+        if search and not _matches_search(tx, search):
+            continue
+        selected.append((tx_date, account, tx))
+    return selected
+
+
+def journal_rows(
+    transactions: Sequence[Tuple[Any, Any, Any]], presentation: str = SPLIT
+) -> List[List[Any]]:
+    """
+    One row per category amount, so every line carries a single category.
+
+    The split presentation puts the amount in a Penerimaan or Pengeluaran
+    column; the signed one uses a single Jumlah column (expenses negative).
+    """
+    rows = []
+    for tx_date, account, tx in transactions:
+        lines = [(PENERIMAAN, name, amount) for name, amount in (tx.penerimaan or {}).items()]
+        lines += [(PENGELUARAN, name, amount) for name, amount in (tx.pengeluaran or {}).items()]
+        if not lines:
+            lines = [("", "", 0)]
+        for entry_type, category, amount in lines:
+            amount = float(amount or 0)
+            row = [tx_date.isoformat(), account.name, tx.uraian, entry_type.capitalize(), category]
+            if presentation == SIGNED:
+                row.append(presentation_amount(entry_type, amount) if entry_type else 0.0)
+            else:
+                row += [
+                    amount if entry_type == PENERIMAAN else "",
+                    amount if entry_type == PENGELUARAN else "",
+                ]
+            rows.append(row)
+    return rows
+
+
+def category_rows(
+    transactions: Sequence[Tuple[Any, Any, Any]], presentation: str = SPLIT
+) -> List[List[Any]]:
+    """Totals per entry type and category, negative for expenses when signed."""
+    totals: Dict[Tuple[str, str], float] = {}
+    for _, _, tx in transactions:
+        for entry_type in (PENERIMAAN, PENGELUARAN):
+            for name, amount in (getattr(tx, entry_type) or {}).items():
+                key = (entry_type, name)
+                totals[key] = totals.get(key, 0.0) + float(amount or 0)
+    return [
+        [
+            entry_type.capitalize(),
+            name,
+            presentation_amount(entry_type, amount) if presentation == SIGNED else amount,
+        ]
+        for (entry_type, name), amount in sorted(totals.items())
+    ]
+
+
+def to_csv(headers: Sequence[str], rows: Iterable[Sequence[Any]]) -> bytes:
+    """Encode rows as CSV with a UTF-8 BOM so Excel detects the encoding."""
+    buffer = io.StringIO()
+    writer = csv.writer(buffer)
+    writer.writerow(headers)
+    writer.writerows(rows)
+    return buffer.getvalue().encode("utf-8-sig")
+
+
+def build_working_paper(
+    accounts: Iterable[Any],
+    account_name: Optional[str] = None,
+    start_date: Optional[str] = None,
+    end_date: Optional[str] = None,
+    entry_type: Optional[str] = None,
+    search: Optional[str] = None,
+    units: Optional[str] = None,
+    presentation: str = SPLIT,
+    generated_at: Optional[datetime] = None,
+) -> bytes:
+    """
+    Build a zip bundle of the transactions matching a filter.
+
+    ``units`` rounds every amount, as in ``reports.round_report``, and
+    ``presentation`` selects split or signed amount columns.
+
+    The bundle contains:
+        ringkasan.csv: The filter used and totals of the selection
+        transaksi.csv: Journal lines, one per category amount
+        kategori.csv: Totals per category
+
+    The bundle has no bank reconciliations or attachment copies, since the
+    app records neither.
+
+    Raises:
+        KeyError: If ``account_name`` is given but no account has that name
+        ValueError: If ``start_date`` or ``end_date`` is not a valid date
+    """
+    generated_at = generated_at or datetime.now()
+    selected = filter_transactions(
+        accounts, account_name, start_date, end_date, entry_type, search
+    )
+    total_income = sum(sum_categories(tx.penerimaan) for _, _, tx in selected)
+    total_expense = sum(sum_categories(tx.pengeluaran) for _, _, tx in selected)
+
+    summary = [
+        ["Akun", account_name or "Semua akun"],
+        ["Dari tanggal", start_date or ""],
+        ["Sampai tanggal", end_date or ""],
+        ["Jenis", entry_type.capitalize() if entry_type else "Semua"],
+        ["Pencarian", search or ""],
+        ["Jumlah transaksi", len(selected)],
+        ["Total penerimaan", round_amount(total_income, units)],
+        ["Total pengeluaran", round_amount(total_expense, units)],
+        ["Selisih", round_amount(net_from_totals(total_income, total_expense), units)],
+        ["Satuan", "Ribuan Rupiah" if units == "thousands" else "Rupiah"],
+        ["Dibuat pada", generated_at.strftime("%Y-%m-%d %H:%M:%S")],
+    ]
+
+    buffer = io.BytesIO()
+    with zipfile.ZipFile(buffer, "w", zipfile.ZIP_DEFLATED) as bundle:
+        bundle.writestr("ringkasan.csv", to_csv(["Keterangan", "Nilai"], summary))
+        bundle.writestr(
+            "transaksi.csv",
+            to_csv(journal_headers(presentation), round_rows(journal_rows(selected, presentation), units)),
+        )
+        bundle.writestr(
+            "kategori.csv",
+            to_csv(["Jenis", "Kategori", "Total"], round_rows(category_rows(selected, presentation), units)),
+        )
+    return buffer.getvalue()
+
+
+def build_period_package(
+    accounts: Iterable[Any],
+    period: str,
+    account_name: Optional[str] = None,
+    presentation: str = SPLIT,
+    generated_at: Optional[datetime] = None,
+) -> bytes:
+    """
+    Build the closing package an external accountant asks for, for one period.
+
+    The bundle contains:
+        ringkasan.csv: The period covered and its totals
+        jurnal.csv: Journal lines of the period, one per category amount
+        saldo_akun.csv: Opening balance, receipts, payments and closing balance per account
+        kategori.csv: Totals per category for the period
+
+    saldo_akun.csv stands in for the trial balance. The package has no bank
+    reconciliations or attachment copies, since the app records neither.
+
+    Args:
+        period: A month (YYYY-MM) or a year (YYYY)
+        presentation: Split or signed amount columns in the journal and category files
+
+    Raises:
+        ValueError: If ``period`` is not a valid month or year
+        KeyError: If ``account_name`` is given but no account has that name
+    """
+    generated_at = generated_at or datetime.now()
+    start, end = period_range(period)
+    selected = select_accounts(accounts, account_name)
+    transactions = filter_transactions(selected, None, start.isoformat(), end.isoformat())
+
+    balances = []
+    for account in selected:
+        report = build_report([account], None, start.isoformat(), end.isoformat())
+        balances.append(
+            [
+                account.name,
+                report["opening_balance"],
+                report["totals"]["income"],
+                report["totals"]["expense"],
+                report["closing_balance"],
+            ]
+        )
+    total_income = sum(row[2] for row in balances)
+    total_expense = sum(row[3] for row in balances)
+
+    summary = [
+        ["Periode", period.strip()],
+        ["Dari tanggal", start.isoformat()],
+        ["Sampai tanggal", end.isoformat()],
+        ["Akun", account_name or "Semua akun"],
+        ["Jumlah transaksi", len(transactions)],
+        ["Saldo awal", sum(row[1] for row in balances)],
+        ["Total penerimaan", total_income],
+        ["Total pengeluaran", total_expense],
+        ["Saldo akhir", sum(row[4] for row in balances)],
+        ["Dibuat pada", generated_at.strftime("%Y-%m-%d %H:%M:%S")],
+    ]
+
+    buffer = io.BytesIO()
+    with zipfile.ZipFile(buffer, "w", zipfile.ZIP_DEFLATED) as bundle:
+        bundle.writestr("ringkasan.csv", to_csv(["Keterangan", "Nilai"], summary))
+        bundle.writestr(
+            "jurnal.csv",
+            to_csv(journal_headers(presentation), journal_rows(transactions, presentation)),
+        )
+        bundle.writestr(
+            "saldo_akun.csv",
+            to_csv(["Akun", "Saldo Awal", "Penerimaan", "Pengeluaran", "Saldo Akhir"], balances),
+        )
+        bundle.writestr(
+            "kategori.csv",
+            to_csv(["Jenis", "Kategori", "Total"], category_rows(transactions, presentation)),
+        )
+    return buffer.getvalue()
@@ -0,0 +1,9 @@
+"""
+Text formatting of amounts shared by the report renderers and insights.
+"""
+
+
+def format_rupiah(amount: float) -> str:
+    """Format an amount as Indonesian Rupiah, e.g. Rp1.250.000 or -Rp500."""
+    formatted = f"{abs(amount):,.0f}".replace(",", ".")
+    return f"-Rp{formatted}" if round(amount) < 0 else f"Rp{formatted}"
@@ -1,6 +1,7 @@
 import { useState } from 'react';
 import { ChevronDownIcon, PlusIcon } from '@heroicons/react/24/outline';
-import { AccountData, TransactionItem } from '@/types';
+import { AccountData } from '@/types';
+import { calculateBalance } from '@/utils/balance';
 
 interface AccountSelectorProps {
   accounts: AccountData[];
@@ -25,14 +26,10 @@ export const AccountSelector = ({
     account.name.toLowerCase().includes(searchTerm.toLowerCase())
   );
 
-  const calculateBalance = (account: AccountData): string => {
+  const formatBalance = (account: AccountData): string => {
     if (!account?.transactions?.length) return 'Rp 0';
     
-    const balance = account.transactions.reduce((sum: number, tx: TransactionItem) => {
-      const income = Object.values(tx.penerimaan || {}).reduce((a: number, b: number) => a + (Number(b) || 0), 0);
-      const expense = Object.values(tx.pengeluaran || {}).reduce((a: number, b: number) => a + (Number(b) || 0), 0);
-      return sum + (income - expense);
-    }, 0);
+    const balance = calculateBalance(account.transactions);
     
     return new Intl.NumberFormat('id-ID', {
       style: 'currency',
@@ -54,7 +51,7 @@ export const AccountSelector = ({
           </p>
           {selectedAccount && (
             <p className="text-xs text-gray-500 truncate">
-              Saldo: {calculateBalance(selectedAccount)}
+              Saldo: {formatBalance(selectedAccount)}
             </p>
           )}
         </div>
@@ -97,7 +94,7 @@ export const AccountSelector = ({
                   </span>
                 </div>
                 <div className="text-xs text-gray-500 truncate">
-                  {calculateBalance(account)}
+                  {formatBalance(account)}
                 </div>
               </div>
             ))}
@@ -0,0 +1,126 @@
+import { useState } from 'react';
+import { toast } from 'react-hot-toast';
+import { ArrowsRightLeftIcon } from '@heroicons/react/24/outline';
+import { compareBackups } from '@/services/api';
+import type { BackupComparison, BackupTransaction } from '@/services/api';
+import { getNetAmount } from '@/utils/balance';
+
+const formatCurrency = (amount: number): string =>
+  new Intl.NumberFormat('id-ID', {
+    style: 'currency',
+    currency: 'IDR',
+    minimumFractionDigits: 0,
+    maximumFractionDigits: 0
+  }).format(amount);
+
+const describe = (tx: BackupTransaction): string =>
+  `${tx.tanggal} · ${tx.uraian || 'Tanpa keterangan'} · ${formatCurrency(getNetAmount(tx))}`;
+
+// Compare two exported Excel backups and list what changed from A to B
+export const BackupCompare = () => {
+  const [fileA, setFileA] = useState<File | null>(null);
+  const [fileB, setFileB] = useState<File | null>(null);
+  const [isComparing, setIsComparing] = useState(false);
+  const [result, setResult] = useState<BackupComparison | null>(null);
+
+  const handleCompare = async () => {
+    if (!fileA || !fileB) return;
+    setIsComparing(true);
+    try {
+      setResult(await compareBackups(fileA, fileB));
+    } catch (error) {
+      console.error('Error comparing backups:', error);
+      toast.error('Gagal membandingkan file cadangan. Pastikan keduanya file Excel hasil ekspor.');
+      setResult(null);
+    } finally {
+      setIsComparing(false);
+    }
+  };
+
+  const unchanged = result
+    && result.accountsAdded.length === 0
+    && result.accountsRemoved.length === 0
+    && result.accountsChanged.length === 0;
+
+  return (
+    <div className="mt-8 bg-white shadow rounded-lg p-6">
+      <h3 className="text-lg font-medium text-gray-900 mb-1">Bandingkan Cadangan</h3>
+      <p className="text-sm text-gray-600 mb-4">
+        Pilih dua file Excel hasil ekspor untuk melihat akun dan transaksi yang berubah
+      </p>
+      <div className="grid grid-cols-1 sm:grid-cols-2 gap-3">
+        <label className="flex flex-col text-xs text-gray-500">
+          Cadangan lama (A)
+          <input
+            type="file"
+            accept=".xlsx"
+            onChange={(e) => setFileA(e.target.files?.[0] || null)}
+            className="mt-1 text-sm text-gray-700"
+          />
+        </label>
+        <label className="flex flex-col text-xs text-gray-500">
+          Cadangan baru (B)
+          <input
+            type="file"
+            accept=".xlsx"
+            onChange={(e) => setFileB(e.target.files?.[0] || null)}
+            className="mt-1 text-sm text-gray-700"
+          />
+        </label>
+      </div>
+      <button
+        type="button"
+        onClick={handleCompare}
+        disabled={!fileA || !fileB || isComparing}
+        className="mt-4 inline-flex items-center px-4 py-2 border border-gray-300 shadow-sm text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed"
+      >
+        <ArrowsRightLeftIcon className="-ml-1 mr-2 h-4 w-4 text-gray-500" />
+        {isComparing ? 'Membandingkan...' : 'Bandingkan'}
+      </button>
+
+      {result && (
+        <div className="mt-4 space-y-4 text-sm text-gray-700">
+          <p>
+            Saldo total: {formatCurrency(result.summary.balanceA)} → {formatCurrency(result.summary.balanceB)}
+            {' '}({result.summary.transactionsAdded} ditambah, {result.summary.transactionsRemoved} dihapus,
+            {' '}{result.summary.transactionsChanged} diubah)
+          </p>
+          {unchanged && <p className="text-gray-500 italic">Tidak ada perubahan.</p>}
+          {result.accountsAdded.map(account => (
+            <p key={`added-${account.name}`} className="text-green-700">
+              + Akun baru "{account.name}": {account.transactions} transaksi, saldo {formatCurrency(account.balance)}
+            </p>
+          ))}
+          {result.accountsRemoved.map(account => (
+            <p key={`removed-${account.name}`} className="text-red-700">
+              − Akun dihapus "{account.name}": {account.transactions} transaksi, saldo {formatCurrency(account.balance)}
+            </p>
+          ))}
+          {result.accountsChanged.map(account => (
+            <div key={account.name} className="border-t border-gray-200 pt-3">
+              <p className="font-medium text-gray-900">
+                {account.name}: {formatCurrency(account.balanceA)} → {formatCurrency(account.balanceB)}
+                {' '}({account.balanceDelta >= 0 ? '+' : ''}{formatCurrency(account.balanceDelta)})
+              </p>
+              <ul className="mt-1 space-y-1">
+                {account.added.map((tx, idx) => (
+                  <li key={`a-${idx}`} className="text-green-700">+ {describe(tx)}</li>
+                ))}
+                {account.removed.map((tx, idx) => (
+                  <li key={`r-${idx}`} className="text-red-700">− {describe(tx)}</li>
+                ))}
+                {account.changed.map((change, idx) => (
+                  <li key={`c-${idx}`} className="text-yellow-700">
+                    ~ {describe(change.before)} → {describe(change.after)}
+                  </li>
+                ))}
+              </ul>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
+
+export default BackupCompare;
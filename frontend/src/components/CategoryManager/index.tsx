@@ -1,11 +1,15 @@
-import { useState } from 'react';
+import { useState, useRef, ChangeEvent } from 'react';
 import { useAppContext } from '@/contexts/AppContext';
-import { PlusIcon, PencilIcon, TrashIcon, ArrowPathIcon } from '@heroicons/react/24/outline';
+import { useSettings } from '@/contexts/SettingsContext';
+import { PlusIcon, PencilIcon, TrashIcon, ArrowPathIcon, ArrowDownTrayIcon, ArrowUpTrayIcon, BellAlertIcon } from '@heroicons/react/24/outline';
 import { v4 as uuidv4 } from 'uuid';
 import { toast } from 'react-hot-toast';
+import { buildCategoryChart, parseCategoryChart, CategoryChartEntry } from '@/utils/categoryChart';
+import { getNumberValidationError } from '@/utils/validators';
 
 export const CategoryManager = () => {
   const { state, dispatch } = useAppContext();
+  const { spendingLimits, setSpendingLimit, renameSpendingLimit } = useSettings();
   const [isAdding, setIsAdding] = useState(false);
   const [isLoading, setIsLoading] = useState(false);
   const [editingId, setEditingId] = useState<string | null>(null);
@@ -16,6 +20,7 @@ export const CategoryManager = () => {
   const [formErrors, setFormErrors] = useState({
     name: ''
   });
+  const chartInputRef = useRef<HTMLInputElement>(null);
 
   const validateForm = () => {
     let isValid = true;
@@ -121,6 +126,11 @@ export const CategoryManager = () => {
         return;
       }
       
+      // Limits only apply to pengeluaran categories, so only move one used as such
+      const isPengeluaranCategory = state.accounts.some(account =>
+        account.transactions.some(tx => tx.pengeluaran && oldName in tx.pengeluaran)
+      );
+
       // Update all transactions that use this category
       const updatedAccounts = state.accounts.map(account => {
         const updatedTransactions = account.transactions.map(tx => {
@@ -166,6 +176,9 @@ export const CategoryManager = () => {
           } 
         });
       });
+      if (isPengeluaranCategory) {
+        renameSpendingLimit(oldName, nameToUse);
+      }
       
       setEditingId(null);
       setFormData({ name: '', type: 'pengeluaran' });
@@ -197,6 +210,12 @@ export const CategoryManager = () => {
       };
 
       dispatch({ type: 'UPDATE_CATEGORY', payload: updatedCategory });
+      // Limits only apply to pengeluaran categories
+      if (updatedCategory.type === 'pengeluaran') {
+        renameSpendingLimit(contextCategory.name, nameToUse);
+      } else {
+        setSpendingLimit(contextCategory.name, null);
+      }
       setEditingId(null);
       setFormData({ name: '', type: 'pengeluaran' });
       setFormErrors({ name: '' });
@@ -208,7 +227,11 @@ export const CategoryManager = () => {
     if (window.confirm('Apakah Anda yakin ingin menghapus kategori ini?')) {
       setIsLoading(true);
       try {
+        const category = state.categories.find(cat => cat.id === id);
         dispatch({ type: 'REMOVE_CATEGORY', payload: { id } });
+        if (category?.type === 'pengeluaran') {
+          setSpendingLimit(category.name, null);
+        }
         toast.success('Kategori berhasil dihapus');
       } catch (error) {
         console.error('Error deleting category:', error);
@@ -227,6 +250,39 @@ export const CategoryManager = () => {
     });
   };
 
+  // Ask for the monthly spending limit of a pengeluaran category (empty or 0 removes it)
+  const handleSetSpendingLimit = (categoryName: string) => {
+    const current = spendingLimits[categoryName];
+    const input = prompt(
+      `Batas pengeluaran per bulan untuk "${categoryName}" (kosongkan untuk menghapus):`,
+      current ? String(current) : ''
+    );
+    if (input === null) return;
+
+    const value = input.trim();
+    const error = value ? getNumberValidationError(value, 'Batas pengeluaran') : null;
+    if (error) {
+      toast.error(`${error}. Contoh: 1500000`);
+      return;
+    }
+    const limit = value ? Number(value) : 0;
+    if (!limit) {
+      setSpendingLimit(categoryName, null);
+      toast.success(`Batas pengeluaran "${categoryName}" dihapus`);
+      return;
+    }
+    setSpendingLimit(categoryName, limit);
+    toast.success(`Batas pengeluaran "${categoryName}" disimpan`);
+  };
+
+  const formatLimit = (amount: number): string =>
+    new Intl.NumberFormat('id-ID', {
+      style: 'currency',
+      currency: 'IDR',
+      minimumFractionDigits: 0,
+      maximumFractionDigits: 0
+    }).format(amount);
+
   const cancelEditing = () => {
     setEditingId(null);
     setFormData({ name: '', type: 'pengeluaran' });
@@ -280,6 +336,86 @@ export const CategoryManager = () => {
     return allCategories;
   };
 
+  // Export accounts and categories (from the list and from all transactions) as JSON
+  const handleExportChart = () => {
+    const entries: CategoryChartEntry[] = state.categories.map(({ name, type, description }) => ({
+      name,
+      type,
+      description,
+    }));
+    const seen = new Set(entries.map(entry => `${entry.type}:${entry.name.toLowerCase()}`));
+
+    state.accounts.forEach(account => {
+      account.transactions.forEach(tx => {
+        (['penerimaan', 'pengeluaran'] as const).forEach(type => {
+          Object.keys(tx[type] || {}).forEach(name => {
+            const key = `${type}:${name.toLowerCase()}`;
+            if (!seen.has(key)) {
+              seen.add(key);
+              entries.push({ name, type });
+            }
+          });
+        });
+      });
+    });
+
+    const chart = buildCategoryChart(state.accounts.map(acc => acc.name), entries);
+    const blob = new Blob([JSON.stringify(chart, null, 2)], { type: 'application/json' });
+    const url = window.URL.createObjectURL(blob);
+    const a = document.createElement('a');
+    a.href = url;
+    a.download = `bagan_akun_${new Date().toISOString().split('T')[0]}.json`;
+    document.body.appendChild(a);
+    a.click();
+
+    // Cleanup
+    window.URL.revokeObjectURL(url);
+    document.body.removeChild(a);
+
+    toast.success('Bagan akun berhasil diekspor');
+  };
+
+  // Import a chart file, adding accounts and categories that do not exist yet
+  const handleImportChart = async (e: ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = '';
+    if (!file) return;
+
+    setIsLoading(true);
+    try {
+      const chart = parseCategoryChart(await file.text());
+
+      const existingCategories = new Set(
+        state.categories.map(cat => `${cat.type}:${cat.name.toLowerCase()}`)
+      );
+      let addedCategories = 0;
+      chart.categories.forEach(entry => {
+        const key = `${entry.type}:${entry.name.toLowerCase()}`;
+        if (existingCategories.has(key)) return;
+        existingCategories.add(key);
+        dispatch({ type: 'ADD_CATEGORY', payload: { id: uuidv4(), ...entry } });
+        addedCategories++;
+      });
+
+      const existingAccounts = new Set(state.accounts.map(acc => acc.name.toLowerCase()));
+      const newAccounts = chart.accounts
+        .filter(name => !existingAccounts.has(name.toLowerCase()))
+        .map(name => ({ name, transactions: [], balance: 0 }));
+      if (newAccounts.length > 0) {
+        dispatch({ type: 'SET_ACCOUNTS', payload: [...state.accounts, ...newAccounts] });
+      }
+
+      toast.success(
+        `Bagan akun diimpor: ${newAccounts.length} akun dan ${addedCategories} kategori baru`
+      );
+    } catch (error) {
+      console.error('Error importing chart:', error);
+      toast.error(error instanceof Error ? error.message : 'Gagal mengimpor bagan akun');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
   return (
     <div className="space-y-6 p-4 md:p-6 bg-gray-50 rounded-lg">
       <div className="flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4">
@@ -287,28 +423,57 @@ export const CategoryManager = () => {
           <h2 className="text-2xl font-bold text-gray-800">Kelola Kategori</h2>
           <p className="text-sm text-gray-500 mt-1">Tambah, edit, atau hapus kategori penerimaan dan pengeluaran</p>
         </div>
-        <button
-          onClick={() => {
-            setIsAdding(!isAdding);
-            setEditingId(null);
-            setFormData({ name: '', type: 'pengeluaran' });
-            setFormErrors({ name: '' });
-          }}
-          className="px-4 py-2.5 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors duration-200 flex items-center gap-2 text-sm font-medium shadow-sm w-full sm:w-auto justify-center"
-          disabled={isLoading}
-        >
-          {isLoading ? (
-            <>
-              <ArrowPathIcon className="h-4 w-4 animate-spin" />
-              Memproses...
-            </>
-          ) : (
-            <>
-              <PlusIcon className="h-5 w-5" />
-              {isAdding ? 'Batal' : 'Tambah Kategori'}
-            </>
-          )}
-        </button>
+        <div className="flex flex-col sm:flex-row gap-2 w-full sm:w-auto">
+          <button
+            type="button"
+            onClick={handleExportChart}
+            className="px-4 py-2.5 bg-white text-gray-700 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors duration-200 flex items-center gap-2 text-sm font-medium shadow-sm justify-center"
+            disabled={isLoading}
+            title="Unduh daftar akun dan kategori sebagai file JSON"
+          >
+            <ArrowDownTrayIcon className="h-5 w-5" />
+            Ekspor Bagan Akun
+          </button>
+          <button
+            type="button"
+            onClick={() => chartInputRef.current?.click()}
+            className="px-4 py-2.5 bg-white text-gray-700 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors duration-200 flex items-center gap-2 text-sm font-medium shadow-sm justify-center"
+            disabled={isLoading}
+            title="Tambahkan akun dan kategori dari file bagan akun"
+          >
+            <ArrowUpTrayIcon className="h-5 w-5" />
+            Impor Bagan Akun
+          </button>
+          <input
+            ref={chartInputRef}
+            type="file"
+            accept="application/json,.json"
+            className="hidden"
+            onChange={handleImportChart}
+          />
+          <button
+            onClick={() => {
+              setIsAdding(!isAdding);
+              setEditingId(null);
+              setFormData({ name: '', type: 'pengeluaran' });
+              setFormErrors({ name: '' });
+            }}
+            className="px-4 py-2.5 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors duration-200 flex items-center gap-2 text-sm font-medium shadow-sm w-full sm:w-auto justify-center"
+            disabled={isLoading}
+          >
+            {isLoading ? (
+              <>
+                <ArrowPathIcon className="h-4 w-4 animate-spin" />
+                Memproses...
+              </>
+            ) : (
+              <>
+                <PlusIcon className="h-5 w-5" />
+                {isAdding ? 'Batal' : 'Tambah Kategori'}
+              </>
+            )}
+          </button>
+        </div>
       </div>
 
       {isAdding && (
@@ -536,8 +701,24 @@ export const CategoryManager = () => {
                     </div>
                   ) : (
                     <>
-                      <span className="text-sm text-gray-800">{category.name}</span>
+                      <div>
+                        <span className="text-sm text-gray-800">{category.name}</span>
+                        {spendingLimits[category.name] > 0 && (
+                          <p className="text-xs text-amber-600">
+                            Batas: {formatLimit(spendingLimits[category.name])}/bulan
+                          </p>
+                        )}
+                      </div>
                       <div className="flex space-x-1">
+                        <button
+                          type="button"
+                          onClick={() => handleSetSpendingLimit(category.name)}
+                          className="p-1.5 text-amber-500 hover:text-amber-600 rounded-full hover:bg-amber-50 transition-colors duration-150"
+                          title="Atur batas pengeluaran bulanan"
+                          disabled={isLoading}
+                        >
+                          <BellAlertIcon className="h-4 w-4" />
+                        </button>
                         <button
                           type="button"
                           onClick={() => startEditing(category)}
@@ -573,6 +754,8 @@ export const CategoryManager = () => {
           <li>Klik ikon pensil untuk mengedit nama kategori</li>
           <li>Klik ikon tong sampah untuk menghapus kategori</li>
           <li>Kategori yang digunakan dalam transaksi tidak dapat dihapus</li>
+          <li>Klik ikon lonceng pada kategori pengeluaran untuk mengatur batas pengeluaran bulanan</li>
+          <li>Gunakan Ekspor/Impor Bagan Akun untuk memakai daftar akun dan kategori yang sama di beberapa file</li>
         </ul>
       </div>
     </div>
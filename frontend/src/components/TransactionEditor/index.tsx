@@ -12,12 +12,18 @@ import {
   ArrowsUpDownIcon,
   PlusIcon,
   FunnelIcon,
-  ArrowPathIcon
+  ArrowPathIcon,
+  ArrowDownTrayIcon
 } from '@heroicons/react/24/outline';
 import { toast } from 'react-hot-toast';
+import { saveAs } from 'file-saver';
+import { exportWorkingPaper } from '@/services/api';
 import { ConfirmationDialog } from '@/components/common/ConfirmationDialog';
 import { getDateValidationError } from '@/utils/validators';
 import { useAppContext } from '@/contexts/AppContext';
+import { useSettings } from '@/contexts/SettingsContext';
+import { checkSpendingLimits } from '@/utils/spendingLimits';
+import { getNetAmount, toPresentationAmount, withRunningBalance } from '@/utils/balance';
 
 type SortField = 'tanggal' | 'jumlah' | 'saldo_berjalan' | 'uraian' | 'kategori';
 type SortDirection = 'asc' | 'desc';
@@ -47,11 +53,13 @@ interface TransactionEditorProps {
 }
 
 export const TransactionEditor = ({ transactions, onSave, accountName }: TransactionEditorProps) => {
-  const { state: { categories } } = useAppContext();
+  const { state: { categories, accounts } } = useAppContext();
+  const { spendingLimits, amountPresentation } = useSettings();
   const [showCategoryManager, setShowCategoryManager] = useState(false);
   const navigate = useNavigate();
   const [isAdding, setIsAdding] = useState(false);
   const [showFilters, setShowFilters] = useState(true);
+  const [isExportingPaper, setIsExportingPaper] = useState(false);
   
   // State for duplicate transaction warning
   const [duplicateWarning, setDuplicateWarning] = useState<{
@@ -65,20 +73,10 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
   });
   
   // Calculate running balance for transactions
-  const transactionsWithRunningBalance = useMemo(() => {
-    let runningBalance = 0;
-    return transactions.map(tx => {
-      const penerimaan = Object.values(tx.penerimaan || {}).reduce((sum, val) => sum + (Number(val) || 0), 0);
-      const pengeluaran = Object.values(tx.pengeluaran || {}).reduce((sum, val) => sum + (Number(val) || 0), 0);
-      const jumlah = penerimaan - pengeluaran;
-      runningBalance += jumlah;
-      return {
-        ...tx,
-        jumlah,
-        saldo_berjalan: runningBalance
-      };
-    });
-  }, [transactions]);
+  const transactionsWithRunningBalance = useMemo(
+    () => withRunningBalance(transactions),
+    [transactions]
+  );
   
   // Filter and sort state
   const searchInputRef = useRef<HTMLInputElement>(null);
@@ -245,19 +243,7 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
 
   // Update local state when transactions prop changes
   useEffect(() => {
-    let runningBalance = 0;
-    const updatedTransactions = transactions.map(tx => {
-      const penerimaan = Object.values(tx.penerimaan || {}).reduce((sum: number, val) => sum + (Number(val) || 0), 0);
-      const pengeluaran = Object.values(tx.pengeluaran || {}).reduce((sum: number, val) => sum + (Number(val) || 0), 0);
-      const jumlah = penerimaan - pengeluaran;
-      runningBalance += jumlah;
-      return {
-        ...tx,
-        jumlah,
-        saldo_berjalan: runningBalance
-      };
-    });
-    setEditedTransactions(updatedTransactions);
+    setEditedTransactions(withRunningBalance(transactions));
   }, [transactions]);
 
 
@@ -442,6 +428,19 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
 
     setEditedTransactions(updated);
     onSave(updated);
+
+    // Warn when this transaction pushes a category over its monthly limit (across all accounts)
+    const allTransactions = [
+      ...accounts.filter(account => account.name !== accountName).flatMap(account => account.transactions),
+      ...updated
+    ];
+    checkSpendingLimits(newTransaction, allTransactions, spendingLimits).forEach(warning => {
+      toast(
+        `Pengeluaran "${warning.category}" bulan ${warning.month} sebesar ${formatCurrency(warning.total)} ` +
+        `melebihi batas ${formatCurrency(warning.limit)}`,
+        { icon: '⚠️', duration: 6000 }
+      );
+    });
     
     // Reset the form
     setSimpleForm({
@@ -454,7 +453,7 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
     
     setIsAdding(false);
     setEditingId(null);
-  }, [editedTransactions, onSave]);
+  }, [editedTransactions, onSave, accounts, accountName, spendingLimits]);
 
   const handleSaveTransaction = useCallback((e: React.FormEvent): void => {
     e.preventDefault();
@@ -504,9 +503,7 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
     }
 
     // Calculate total amount
-    const penerimaanTotal = Object.values(txData.penerimaan).reduce((sum, val) => sum + (Number(val) || 0), 0);
-    const pengeluaranTotal = Object.values(txData.pengeluaran).reduce((sum, val) => sum + (Number(val) || 0), 0);
-    txData.jumlah = penerimaanTotal - pengeluaranTotal;
+    txData.jumlah = getNetAmount(txData);
 
     // Check for duplicates for both new and updated transactions
     const isDuplicate = checkForDuplicates(txData as TransactionItem, editingId || undefined);
@@ -540,6 +537,31 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
   }, [isAdding, editingId]);
 
   // Filter and search controls component
+  // Export the transactions matching the current filters as a working paper bundle
+  const handleExportWorkingPaper = useCallback(async () => {
+    setIsExportingPaper(true);
+    try {
+      const blob = await exportWorkingPaper(
+        [{ name: accountName, transactions }],
+        {
+          account: accountName,
+          startDate: filters.startDate || undefined,
+          endDate: filters.endDate || undefined,
+          entryType: filters.transactionType === 'all' ? undefined : filters.transactionType,
+          search: filters.searchQuery || undefined,
+          presentation: amountPresentation,
+        }
+      );
+      saveAs(blob, `kertas_kerja_${accountName}_${new Date().toISOString().split('T')[0]}.zip`);
+      toast.success('Kertas kerja berhasil diekspor');
+    } catch (error) {
+      console.error('Working paper export error:', error);
+      toast.error('Gagal mengekspor kertas kerja');
+    } finally {
+      setIsExportingPaper(false);
+    }
+  }, [accountName, transactions, filters, amountPresentation]);
+
   const FilterControls = (): JSX.Element => {
     const hasActiveFilters = filters.searchQuery || 
                            filters.transactionType !== 'all' || 
@@ -588,8 +610,18 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
               </div>
             </div>
 
-            {/* Reset Filters Button */}
-            <div className="flex items-end justify-end md:justify-end w-full md:w-1/3">
+            {/* Export and Reset Filters Buttons */}
+            <div className="flex flex-col sm:flex-row items-end justify-end md:justify-end gap-2 w-full md:w-1/3">
+              <button
+                type="button"
+                onClick={handleExportWorkingPaper}
+                disabled={isExportingPaper || transactions.length === 0}
+                title="Unduh transaksi sesuai filter sebagai file ZIP berisi CSV"
+                className="w-full md:w-auto inline-flex items-center justify-center px-4 py-2.5 rounded-lg text-sm font-medium bg-white border border-gray-300 text-gray-700 hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 shadow-sm transition-all duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
+              >
+                <ArrowDownTrayIcon className="h-4 w-4 mr-2" />
+                {isExportingPaper ? 'Mengekspor...' : 'Kertas Kerja'}
+              </button>
               <button
                 type="button"
                 onClick={() => {
@@ -778,20 +810,24 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
                 ) : <ArrowsUpDownIcon className="ml-2 h-4 w-4 text-gray-400" />}
               </div>
             </th>
-            <th 
-              scope="col" 
-              className="px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:bg-gray-50"
-              onClick={() => toggleSort('jumlah')}
-            >
-              <div className="flex items-center justify-end">
-                Jumlah
-                {filters.sortField === 'jumlah' ? (
-                  filters.sortDirection === 'asc' 
-                    ? <ChevronUpIcon className="ml-2 h-5 w-5 text-blue-600 font-bold" /> 
-                    : <ChevronDownIcon className="ml-2 h-5 w-5 text-blue-600 font-bold" />
-                ) : <ArrowsUpDownIcon className="ml-2 h-4 w-4 text-gray-400" />}
-              </div>
-            </th>
+            {/* One signed column, or separate penerimaan/pengeluaran columns */}
+            {(amountPresentation === 'signed' ? ['Jumlah'] : ['Penerimaan', 'Pengeluaran']).map(label => (
+              <th 
+                key={label}
+                scope="col" 
+                className="px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:bg-gray-50"
+                onClick={() => toggleSort('jumlah')}
+              >
+                <div className="flex items-center justify-end">
+                  {label}
+                  {filters.sortField === 'jumlah' ? (
+                    filters.sortDirection === 'asc' 
+                      ? <ChevronUpIcon className="ml-2 h-5 w-5 text-blue-600 font-bold" /> 
+                      : <ChevronDownIcon className="ml-2 h-5 w-5 text-blue-600 font-bold" />
+                  ) : <ArrowsUpDownIcon className="ml-2 h-4 w-4 text-gray-400" />}
+                </div>
+              </th>
+            ))}
             <th 
               scope="col" 
               className="px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider cursor-pointer hover:bg-gray-50"
@@ -814,7 +850,7 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
         <tbody className="bg-white divide-y divide-gray-200">
           {filteredAndSortedTransactions.length === 0 ? (
             <tr>
-              <td colSpan={6} className="px-6 py-4 text-center text-sm text-gray-500">
+              <td colSpan={amountPresentation === 'signed' ? 6 : 7} className="px-6 py-4 text-center text-sm text-gray-500">
                 Tidak ada transaksi. Klik tombol "Tambah Transaksi" untuk menambahkan transaksi baru.
               </td>
             </tr>
@@ -827,6 +863,10 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
               const amount = isPenerimaan 
                 ? Object.values(transaction.penerimaan || {})[0] 
                 : Object.values(transaction.pengeluaran || {})[0];
+              const signedAmount = toPresentationAmount(
+                isPenerimaan ? 'penerimaan' : 'pengeluaran',
+                toNumber(amount?.toString() || '0')
+              );
               
               return (
                 <tr 
@@ -859,13 +899,28 @@ export const TransactionEditor = ({ transactions, onSave, accountName }: Transac
                       {category || '-'}
                     </span>
                   </td>
-                  <td className="px-6 py-4 whitespace-nowrap text-right">
-                    <div className={`text-sm font-medium ${
-                      isPenerimaan ? 'text-green-600' : 'text-red-600'
-                    }`}>
-                      {isPenerimaan ? '' : '-'}{formatCurrency(Math.abs(toNumber(amount?.toString() || '0')))}
-                    </div>
-                  </td>
+                  {amountPresentation === 'signed' ? (
+                    <td className="px-6 py-4 whitespace-nowrap text-right">
+                      <div className={`text-sm font-medium ${
+                        signedAmount >= 0 ? 'text-green-600' : 'text-red-600'
+                      }`}>
+                        {formatCurrency(signedAmount)}
+                      </div>
+                    </td>
+                  ) : (
+                    <>
+                      <td className="px-6 py-4 whitespace-nowrap text-right">
+                        <div className="text-sm font-medium text-green-600">
+                          {isPenerimaan ? formatCurrency(toNumber(amount?.toString() || '0')) : ''}
+                        </div>
+                      </td>
+                      <td className="px-6 py-4 whitespace-nowrap text-right">
+                        <div className="text-sm font-medium text-red-600">
+                          {isPenerimaan ? '' : formatCurrency(toNumber(amount?.toString() || '0'))}
+                        </div>
+                      </td>
+                    </>
+                  )}
                   <td className="px-6 py-4 whitespace-nowrap text-right">
                     <div className="text-sm font-medium text-gray-900">
                       {formatCurrency(transaction.saldo_berjalan?.toString() || '0')}
@@ -1,5 +1,6 @@
 import { createContext, useContext, useReducer} from 'react';
 import type { TransactionItem } from '../types';
+import { calculateBalance, getNetAmount } from '../utils/balance';
 
 interface AccountBase {
   name: string;
@@ -49,21 +50,6 @@ const AppContext = createContext<{
   dispatch: React.Dispatch<AppAction>;
 } | undefined>(undefined);
 
-// Helper to calculate account balance from transactions
-const calculateAccountBalance = (transactions: TransactionItem[]): number => {
-  return transactions.reduce((balance, tx) => {
-    const income = Object.values(tx.penerimaan || {}).reduce(
-      (sum: number, val) => sum + (Number(val) || 0), 
-      0
-    );
-    const expense = Object.values(tx.pengeluaran || {}).reduce(
-      (sum: number, val) => sum + (Number(val) || 0), 
-      0
-    );
-    return balance + (income - expense);
-  }, 0);
-};
-
 // Normalize transaction data to ensure consistent format
 const normalizeTransaction = (tx: Partial<TransactionItem>): TransactionItem => {
   const normalizedTx: TransactionItem = {
@@ -95,15 +81,7 @@ const normalizeTransaction = (tx: Partial<TransactionItem>): TransactionItem =>
   }
 
   // Calculate jumlah
-  const penerimaanTotal = Object.values(normalizedTx.penerimaan).reduce(
-    (sum, val) => sum + (Number(val) || 0),
-    0
-  );
-  const pengeluaranTotal = Object.values(normalizedTx.pengeluaran).reduce(
-    (sum, val) => sum + (Number(val) || 0),
-    0
-  );
-  normalizedTx.jumlah = penerimaanTotal - pengeluaranTotal;
+  normalizedTx.jumlah = getNetAmount(normalizedTx);
 
   return normalizedTx;
 };
@@ -112,7 +90,7 @@ const normalizeTransaction = (tx: Partial<TransactionItem>): TransactionItem =>
 const createAccount = (name: string, transactions: TransactionItem[] = []): AccountBase => ({
   name,
   transactions: transactions.map(normalizeTransaction),
-  balance: calculateAccountBalance(transactions),
+  balance: calculateBalance(transactions),
 });
 
 // Main reducer function
@@ -1,15 +1,24 @@
 import React, { createContext, useContext, useState, useEffect, ReactNode } from 'react';
+import type { SpendingLimits } from '@/utils/spendingLimits';
+import type { AmountPresentation } from '@/utils/balance';
 
 type SettingsContextType = {
   reminderInterval: number;
   setReminderInterval: (interval: number) => void;
   isReminderActive: boolean;
   toggleReminder: (active: boolean) => void;
+  spendingLimits: SpendingLimits;
+  setSpendingLimit: (category: string, limit: number | null) => void;
+  renameSpendingLimit: (oldCategory: string, newCategory: string) => void;
+  amountPresentation: AmountPresentation;
+  setAmountPresentation: (presentation: AmountPresentation) => void;
 };
 
 const defaultSettings = {
   reminderInterval: 300000, // 5 minutes in milliseconds
   isReminderActive: true,
+  spendingLimits: {} as SpendingLimits, // Monthly limit per pengeluaran category
+  amountPresentation: 'split' as AmountPresentation, // Amount columns in exports and prints
 };
 
 const SettingsContext = createContext<SettingsContextType | undefined>(undefined);
@@ -19,7 +28,7 @@ export const SettingsProvider: React.FC<{ children: ReactNode }> = ({ children }
     // Load settings from localStorage if available
     if (typeof window !== 'undefined') {
       const saved = localStorage.getItem('app-settings');
-      return saved ? JSON.parse(saved) : defaultSettings;
+      return saved ? { ...defaultSettings, ...JSON.parse(saved) } : defaultSettings;
     }
     return defaultSettings;
   });
@@ -45,6 +54,43 @@ export const SettingsProvider: React.FC<{ children: ReactNode }> = ({ children }
     }));
   };
 
+  // Set or clear (null/0) the monthly limit for a pengeluaran category
+  const setSpendingLimit = (category: string, limit: number | null) => {
+    setSettings((prev: any) => {
+      const spendingLimits = { ...(prev.spendingLimits || {}) };
+      if (limit && limit > 0) {
+        spendingLimits[category] = limit;
+      } else {
+        delete spendingLimits[category];
+      }
+      return {
+        ...prev,
+        spendingLimits,
+      };
+    });
+  };
+
+  // Keep a category's limit when the category is renamed
+  const renameSpendingLimit = (oldCategory: string, newCategory: string) => {
+    setSettings((prev: any) => {
+      const spendingLimits = { ...(prev.spendingLimits || {}) };
+      if (oldCategory === newCategory || !(oldCategory in spendingLimits)) return prev;
+      spendingLimits[newCategory] = spendingLimits[oldCategory];
+      delete spendingLimits[oldCategory];
+      return {
+        ...prev,
+        spendingLimits,
+      };
+    });
+  };
+
+  const setAmountPresentation = (presentation: AmountPresentation) => {
+    setSettings((prev: any) => ({
+      ...prev,
+      amountPresentation: presentation,
+    }));
+  };
+
   return (
     <SettingsContext.Provider
       value={{
@@ -52,6 +98,11 @@ export const SettingsProvider: React.FC<{ children: ReactNode }> = ({ children }
         setReminderInterval,
         isReminderActive: settings.isReminderActive,
         toggleReminder,
+        spendingLimits: settings.spendingLimits,
+        setSpendingLimit,
+        renameSpendingLimit,
+        amountPresentation: settings.amountPresentation,
+        setAmountPresentation,
       }}
     >
       {children}
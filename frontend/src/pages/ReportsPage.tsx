@@ -1,22 +1,20 @@
 import React, { useState, useRef, useEffect, useCallback, useMemo } from 'react';
 import { useNavigate } from 'react-router-dom';
 import { LineChart, Line, BarChart, Bar, XAxis, YAxis, CartesianGrid, Tooltip, ResponsiveContainer, Legend } from 'recharts';
-import { ArrowLeftIcon, DocumentArrowDownIcon } from '@heroicons/react/24/outline';
+import { ArchiveBoxArrowDownIcon, ArrowLeftIcon, DocumentArrowDownIcon, PrinterIcon, TableCellsIcon } from '@heroicons/react/24/outline';
 import { useAppContext } from '@/contexts/AppContext';
+import { useSettings } from '@/contexts/SettingsContext';
 import { AccountSelector } from '@/components/AccountSelector';
-import type { TransactionItem, AccountData } from '@//types';
+import { saveAs } from 'file-saver';
+import { exportFinancialPackage, exportPeriodPackage, getInsights, getReport, renderReportHtml } from '@/services/api';
+import type { ChartSeries, DisplayUnits, InsightsResult, ReportData } from '@/services/api';
+import type { AccountData } from '@//types';
+import { toPresentationAmount } from '@/utils/balance';
+import type { AmountPresentation } from '@/utils/balance';
 import html2canvas from 'html2canvas';
 import jsPDF from 'jspdf';
 
 // Define interfaces
-interface BalanceReport {
-  date: string;
-  income: number;
-  expense: number;
-  balance: number;
-  runningBalance: number;
-}
-
 interface BalanceByPeriod {
   period: string;
   originalPeriod?: string;
@@ -25,26 +23,14 @@ interface BalanceByPeriod {
   balance: number;
 }
 
-interface ReportData {
+interface ReportView {
   monthly: BalanceByPeriod[];
   yearly: BalanceByPeriod[];
-  running: BalanceReport[];
-  balance_sheet: {
-    assets: Record<string, number>;
-    liabilities: Record<string, number>;
-    equity: Record<string, number>;
-  };
-  income_statement: {
-    income: Record<string, number>;
-    expenses: Record<string, number>;
-    net_income: number;
-  };
-  cash_flow: {
-    operating: number;
-    investing: number;
-    financing: number;
-    net_cash_flow: number;
-  };
+  totals: { income: number; expense: number; net: number };
+  closingBalance: number;
+  incomeByCategory: Record<string, number>;
+  expenseByCategory: Record<string, number>;
+  figuresInThousands: boolean;
 }
 
 interface ChartDataPoint {
@@ -79,15 +65,44 @@ const formatCurrency = (amount: number, shortFormat: boolean = false): string =>
   return isNegative ? `-${formatter.format(absAmount)}` : formatter.format(absAmount);
 };
 
+// Shape the backend report for the tables and charts on this page
+const toReportView = (report: ReportData): ReportView => ({
+  monthly: report.monthly.map(row => ({
+    period: row.label,
+    originalPeriod: row.period,
+    income: row.income,
+    expense: row.expense,
+    balance: row.net
+  })),
+  yearly: report.yearly.map(row => ({
+    period: row.label,
+    income: row.income,
+    expense: row.expense,
+    balance: row.net
+  })),
+  totals: report.totals,
+  closingBalance: report.closingBalance,
+  incomeByCategory: report.incomeByCategory,
+  expenseByCategory: report.expenseByCategory,
+  figuresInThousands: report.figuresInThousands
+});
+
 const ReportsPage: React.FC = () => {
   const navigate = useNavigate();
   const { state, dispatch } = useAppContext();
   const [selectedAccount, setSelectedAccount] = useState<string | null>(null);
-  const [reports, setReports] = useState<ReportData | null>(null);
+  const [reports, setReports] = useState<ReportView | null>(null);
+  const [charts, setCharts] = useState<Partial<ChartSeries>>({});
   const [activeTab, setActiveTab] = useState<'monthly' | 'yearly' | 'running'>('monthly');
   const [exportMode, setExportMode] = useState<boolean>(false);
   const [pdfError, setPdfError] = useState<string | null>(null);
+  const [reportError, setReportError] = useState<string | null>(null);
   const [isExporting, setIsExporting] = useState<boolean>(false);
+  const [isPrinting, setIsPrinting] = useState<boolean>(false);
+  const [isExportingPackage, setIsExportingPackage] = useState<boolean>(false);
+  const [units, setUnits] = useState<DisplayUnits | ''>('');
+  const [insights, setInsights] = useState<InsightsResult | null>(null);
+  const { amountPresentation, setAmountPresentation } = useSettings();
   const [startDate, setStartDate] = useState<string>('');
   const [endDate, setEndDate] = useState<string>('');
   const reportRef = useRef<HTMLDivElement>(null);
@@ -98,159 +113,59 @@ const ReportsPage: React.FC = () => {
     return state.accounts.find(acc => acc.name === selectedAccount) || null;
   }, [selectedAccount, state.accounts]);
 
-  const filteredAccountData = useMemo<AccountData | null>(() => {
-    if (!currentAccountData) return null;
-    if (!startDate && !endDate) return currentAccountData;
-
-    const start = startDate ? new Date(startDate) : null;
-    const end = endDate ? new Date(endDate) : null;
-
-    if (end) {
-      end.setHours(23, 59, 59, 999);
+  // Fetch the health score and insights for the selected account
+  useEffect(() => {
+    if (!currentAccountData) {
+      setInsights(null);
+      return;
     }
-
-    const transactions = currentAccountData.transactions.filter(transaction => {
-      const txDate = new Date(transaction.tanggal);
-      const afterStart = start ? txDate >= start : true;
-      const beforeEnd = end ? txDate <= end : true;
-      return afterStart && beforeEnd;
-    });
-
-    return {
-      ...currentAccountData,
-      transactions
+    let cancelled = false;
+    getInsights(
+      [{ name: currentAccountData.name, transactions: currentAccountData.transactions }],
+      currentAccountData.name
+    )
+      .then(result => {
+        if (!cancelled) setInsights(result);
+      })
+      .catch(error => {
+        console.error('Error loading insights:', error);
+        if (!cancelled) setInsights(null);
+      });
+    return () => {
+      cancelled = true;
     };
-  }, [currentAccountData, startDate, endDate]);
+  }, [currentAccountData]);
 
-  // Generate reports when the selected account or filters change
+  // Load the report and its chart series for the selected account and filters;
+  // the backend aggregates and applies the rounding
   useEffect(() => {
-    const generateReports = (account: AccountData | null): ReportData | null => {
-      if (!account) return null;
-
-      const monthlyData: Record<string, { income: number; expense: number }> = {};
-      const yearlyData: Record<string, { income: number; expense: number }> = {};
-      const runningBalanceData: BalanceReport[] = [];
-      let runningBalance = 0;
-
-      // Sort transactions by date
-      const sortedTransactions = [...account.transactions].sort((a, b) => 
-        new Date(a.tanggal).getTime() - new Date(b.tanggal).getTime()
-      );
-
-      // Process transactions for the current account
-      sortedTransactions.forEach((transaction) => {
-        const date = new Date(transaction.tanggal);
-        const year = date.getFullYear();
-        const month = date.getMonth() + 1;
-        const monthYear = `${year}-${month.toString().padStart(2, '0')}`;
-        const yearStr = year.toString();
-
-        // Calculate income and expense for the transaction
-        const income = Object.values(transaction.penerimaan || {}).reduce(
-          (sum, val) => sum + (typeof val === 'number' ? val : 0), 0
-        );
-        const expense = Object.values(transaction.pengeluaran || {}).reduce(
-          (sum, val) => sum + (typeof val === 'number' ? val : 0), 0
-        );
-        const balance = income - expense;
-
-        // Update running balance
-        runningBalance += balance;
-        
-        // Add to running balance data
-        runningBalanceData.push({
-          date: transaction.tanggal,
-          income,
-          expense,
-          balance,
-          runningBalance
-        });
-
-        // Update monthly data
-        if (!monthlyData[monthYear]) {
-          monthlyData[monthYear] = { income: 0, expense: 0 };
-        }
-        monthlyData[monthYear].income += income;
-        monthlyData[monthYear].expense += expense;
-
-        // Update yearly data
-        if (!yearlyData[yearStr]) {
-          yearlyData[yearStr] = { income: 0, expense: 0 };
-        }
-        yearlyData[yearStr].income += income;
-        yearlyData[yearStr].expense += expense;
-      });
-
-      // Convert to arrays with proper formatting
-      const monthlyReports: BalanceByPeriod[] = Object.entries(monthlyData)
-        .sort(([a], [b]) => a.localeCompare(b))
-        .map(([period, { income, expense }]) => {
-          const [year, month] = period.split('-');
-          const monthNames = ['Jan', 'Feb', 'Mar', 'Apr', 'Mei', 'Jun', 'Jul', 'Agu', 'Sep', 'Okt', 'Nov', 'Des'];
-          const formattedPeriod = `${monthNames[parseInt(month) - 1]} ${year}`;
-          
-          return {
-            period: formattedPeriod,
-            originalPeriod: period,
-            income,
-            expense,
-            balance: income - expense
-          };
-        });
-
-      const yearlyReports: BalanceByPeriod[] = Object.entries(yearlyData)
-        .sort(([a], [b]) => a.localeCompare(b))
-        .map(([period, { income, expense }]) => ({
-          period,
-          income,
-          expense,
-          balance: income - expense
-        }));
-
-      const reportsData: ReportData = {
-        monthly: monthlyReports,
-        yearly: yearlyReports,
-        running: runningBalanceData,
-        balance_sheet: {
-          assets: {},
-          liabilities: {},
-          equity: {}
-        },
-        income_statement: {
-          income: {},
-          expenses: {},
-          net_income: monthlyReports.reduce((sum, { balance }) => sum + balance, 0)
-        },
-        cash_flow: {
-          operating: monthlyReports.reduce((sum, { income }) => sum + income * 0.7, 0),
-          investing: -monthlyReports.reduce((sum, { expense }) => sum + expense * 0.2, 0),
-          financing: monthlyReports.reduce((sum, { income, expense }) => sum + (income * 0.3 - expense * 0.8), 0),
-          net_cash_flow: monthlyReports.reduce((sum, { balance }) => sum + balance, 0)
-        }
-      };
-
-      // Process income and expense categories
-      account.transactions.forEach((transaction: TransactionItem) => {
-        // Process income (penerimaan)
-        Object.entries(transaction.penerimaan).forEach(([category, amount]) => {
-          const value = typeof amount === 'number' ? amount : 0;
-          reportsData.income_statement.income[category] = 
-            (reportsData.income_statement.income[category] || 0) + value;
-        });
-
-        // Process expenses (pengeluaran)
-        Object.entries(transaction.pengeluaran).forEach(([category, amount]) => {
-          const value = typeof amount === 'number' ? amount : 0;
-          reportsData.income_statement.expenses[category] = 
-            (reportsData.income_statement.expenses[category] || 0) + value;
-        });
+    // Never leave the previous account's or period's figures on screen
+    setReports(null);
+    setCharts({});
+    setReportError(null);
+    if (!currentAccountData) return;
+    let cancelled = false;
+    const accounts = [{ name: currentAccountData.name, transactions: currentAccountData.transactions }];
+    const params = {
+      account: currentAccountData.name,
+      startDate: startDate || undefined,
+      endDate: endDate || undefined,
+      units: units || undefined
+    };
+    getReport(accounts, params)
+      .then(report => {
+        if (cancelled) return;
+        setReports(toReportView(report));
+        setCharts(report.charts);
+      })
+      .catch(error => {
+        console.error('Error loading report:', error);
+        if (!cancelled) setReportError('Gagal memuat laporan. Silakan coba lagi.');
       });
-
-      return reportsData;
+    return () => {
+      cancelled = true;
     };
-
-    setReports(generateReports(filteredAccountData));
-  }, [filteredAccountData]);
+  }, [currentAccountData, startDate, endDate, units]);
 
   // Set initial selected account
   useEffect(() => {
@@ -283,7 +198,7 @@ const ReportsPage: React.FC = () => {
 
   const incomeData: ChartDataPoint[] = useMemo(() => {
     if (!reports) return [];
-    return Object.entries(reports.income_statement.income).map(([name, value]) => ({
+    return Object.entries(reports.incomeByCategory).map(([name, value]) => ({
       name,
       value: typeof value === 'number' ? value : 0
     }));
@@ -291,31 +206,32 @@ const ReportsPage: React.FC = () => {
 
   const expenseData: ChartDataPoint[] = useMemo(() => {
     if (!reports) return [];
-    return Object.entries(reports.income_statement.expenses).map(([name, value]) => ({
+    return Object.entries(reports.expenseByCategory).map(([name, value]) => ({
       name,
-      value: Math.abs(typeof value === 'number' ? value : 0)
+      value: typeof value === 'number' ? value : 0
     }));
   }, [reports]);
 
-  // Function to get data for a specific tab
-  const getReportData = (tab: 'monthly' | 'yearly' | 'running') => {
-    if (!reports) return null;
-    
-    if (tab === 'monthly') {
-      return [...reports.monthly].sort((a, b) => 
-        (a.originalPeriod || '').localeCompare(b.originalPeriod || '')
-      );
-    } else if (tab === 'yearly') {
-      return reports.yearly;
-    } else {
-      return reports.running;
-    }
+  // Expense totals are negative in the signed presentation, as in the exports
+  const formatExpense = (amount: number): string =>
+    amountPresentation === 'signed'
+      ? formatCurrency(toPresentationAmount('pengeluaran', amount))
+      : formatCurrency(amount);
+
+  // Table rows for the monthly and yearly tabs
+  const getReportData = (tab: 'monthly' | 'yearly') => {
+    if (!reports) return [];
+    return tab === 'monthly' ? reports.monthly : reports.yearly;
   };
 
+  // Chart series for a tab, as aggregated by the backend
+  const getChartData = (tab: 'monthly' | 'yearly' | 'running') =>
+    (tab === 'running' ? charts.balance : charts[tab]) || [];
+
   // Render a single report section
   const renderReportSection = (tab: 'monthly' | 'yearly' | 'running', title: string) => {
-    const data = getReportData(tab);
-    if (!data) return null;
+    if (!reports) return null;
+    const chartData = getChartData(tab);
 
     return (
       <div key={tab} className={!exportMode ? 'mb-12' : ''}>
@@ -337,7 +253,7 @@ const ReportsPage: React.FC = () => {
               <ResponsiveContainer width="100%" height={400}>
                 {tab === 'running' ? (
                   <LineChart 
-                    data={data}
+                    data={chartData}
                     margin={{
                       top: 20,
                       right: 30,
@@ -375,18 +291,18 @@ const ReportsPage: React.FC = () => {
                     />
                     <Line 
                       type="linear"
-                      dataKey="runningBalance" 
+                      dataKey="balance" 
                       name="Saldo Berjalan" 
                       stroke="#3b82f6"
                       strokeWidth={2}
-                      dot={data.length <= 12}
+                      dot={chartData.length <= 12}
                       activeDot={{ r: 6 }}
                       connectNulls={true}
                     />
                   </LineChart>
                 ) : (
                   <BarChart 
-                    data={data}
+                    data={chartData}
                     margin={{
                       top: 20,
                       right: 30,
@@ -398,7 +314,7 @@ const ReportsPage: React.FC = () => {
                   >
                     <CartesianGrid strokeDasharray="3 3" />
                     <XAxis 
-                      dataKey="period" 
+                      dataKey="label" 
                       tick={{ fontSize: 12 }}
                       tickMargin={10}
                       interval={0}
@@ -472,7 +388,7 @@ const ReportsPage: React.FC = () => {
                       </tr>
                     </thead>
                     <tbody className="bg-white divide-y divide-gray-200">
-                      {data.map((item: any) => (
+                      {getReportData(tab).map((item) => (
                         <tr key={`${tab}-${item.period}`} className="hover:bg-gray-50">
                           <td className="px-3 py-2 whitespace-nowrap text-sm font-medium text-gray-900">
                             {item.period}
@@ -496,16 +412,16 @@ const ReportsPage: React.FC = () => {
                           Total
                         </td>
                         <td className="px-3 py-2 whitespace-nowrap text-sm text-right font-medium text-green-600">
-                          {formatCurrency(data.reduce((sum: number, item: any) => sum + item.income, 0), false)}
+                          {formatCurrency(reports.totals.income, false)}
                         </td>
                         <td className="px-3 py-2 whitespace-nowrap text-sm text-right font-medium text-red-600">
-                          {formatCurrency(data.reduce((sum: number, item: any) => sum + item.expense, 0), false)}
+                          {formatCurrency(reports.totals.expense, false)}
                         </td>
                         <td className={`px-3 py-2 whitespace-nowrap text-sm text-right font-medium ${
-                          data.reduce((sum: number, item: any) => sum + item.balance, 0) >= 0 ? 'text-green-600' : 'text-red-600'
+                          reports.totals.net >= 0 ? 'text-green-600' : 'text-red-600'
                         }`}>
-                          {data.reduce((sum: number, item: any) => sum + item.balance, 0) >= 0 ? '' : '-'}
-                          {formatCurrency(Math.abs(data.reduce((sum: number, item: any) => sum + item.balance, 0)), false)}
+                          {reports.totals.net >= 0 ? '' : '-'}
+                          {formatCurrency(Math.abs(reports.totals.net), false)}
                         </td>
                       </tr>
                     </tbody>
@@ -767,6 +683,102 @@ const ReportsPage: React.FC = () => {
   };
 
   // Render the main content with all reports
+  // Open the server-rendered print layout in a new window and print it
+  const handlePrint = async () => {
+    // Open the window before the request so popup blockers allow it
+    const printWindow = window.open('', '_blank');
+    if (!printWindow) {
+      setPdfError('Jendela cetak diblokir oleh browser. Izinkan pop-up untuk situs ini.');
+      return;
+    }
+
+    setIsPrinting(true);
+    try {
+      const html = await renderReportHtml(
+        'summary',
+        state.accounts.map(account => ({
+          name: account.name,
+          transactions: account.transactions
+        })),
+        {
+          account: selectedAccount,
+          startDate: startDate || undefined,
+          endDate: endDate || undefined,
+          units: units || undefined,
+          presentation: amountPresentation
+        }
+      );
+      printWindow.document.open();
+      printWindow.document.write(html);
+      printWindow.document.close();
+      printWindow.focus();
+      printWindow.print();
+    } catch (error) {
+      console.error('Error printing report:', error);
+      printWindow.close();
+      setPdfError('Gagal menyiapkan laporan untuk dicetak. Silakan coba lagi.');
+    } finally {
+      setIsPrinting(false);
+    }
+  };
+
+  // Download the year-end statement package for the selected account and period
+  const handleExportPackage = async () => {
+    setIsExportingPackage(true);
+    try {
+      const blob = await exportFinancialPackage(
+        state.accounts.map(account => ({
+          name: account.name,
+          transactions: account.transactions
+        })),
+        {
+          account: selectedAccount,
+          startDate: startDate || undefined,
+          endDate: endDate || undefined,
+          units: units || undefined,
+          presentation: amountPresentation
+        }
+      );
+      const dateStr = new Date().toISOString().split('T')[0];
+      saveAs(blob, `paket_laporan_${dateStr}.xlsx`);
+    } catch (error) {
+      console.error('Error exporting financial package:', error);
+      setPdfError('Gagal mengekspor paket laporan. Silakan coba lagi.');
+    } finally {
+      setIsExportingPackage(false);
+    }
+  };
+
+  // Download one month or year as a CSV bundle for the accountant's closing
+  const handleExportPeriod = async () => {
+    const defaultPeriod = (endDate || new Date().toISOString()).slice(0, 7);
+    const period = window.prompt('Periode yang diekspor (YYYY-MM untuk bulan, YYYY untuk tahun):', defaultPeriod);
+    if (!period || !period.trim()) return;
+    if (!/^\d{4}(-\d{2})?$/.test(period.trim())) {
+      setPdfError('Format periode harus YYYY-MM atau YYYY.');
+      return;
+    }
+
+    setIsExportingPackage(true);
+    try {
+      const blob = await exportPeriodPackage(
+        state.accounts.map(account => ({
+          name: account.name,
+          transactions: account.transactions
+        })),
+        period.trim(),
+        selectedAccount,
+        amountPresentation
+      );
+      saveAs(blob, `paket_periode_${period.trim()}.zip`);
+    } catch (error) {
+      console.error('Error exporting period package:', error);
+      setPdfError('Gagal mengekspor paket periode. Silakan coba lagi.');
+    } finally {
+      setIsExportingPackage(false);
+    }
+  };
+
   const renderReports = () => {
     if (!reports) return null;
 
@@ -802,7 +814,7 @@ const ReportsPage: React.FC = () => {
                       <div key={item.name} className="flex justify-between">
                         <span className="text-gray-600">{item.name}</span>
                         <span className="font-medium text-red-600">
-                          - {formatCurrency(item.value)}
+                          {formatExpense(item.value)}
                         </span>
                       </div>
                     ))}
@@ -816,9 +828,9 @@ const ReportsPage: React.FC = () => {
               <div className="flex justify-between">
                 <span className="text-lg font-medium">Saldo Akhir</span>
                 <span className={`text-lg font-bold ${
-                  reports.income_statement.net_income >= 0 ? 'text-green-600' : 'text-red-600'
+                  reports.closingBalance >= 0 ? 'text-green-600' : 'text-red-600'
                 }`}>
-                  {formatCurrency(reports.income_statement.net_income)}
+                  {formatCurrency(reports.closingBalance)}
                 </span>
               </div>
             </div>
@@ -827,8 +839,8 @@ const ReportsPage: React.FC = () => {
           {/* Category Charts */}
           <div id="report-section-category-charts" className="grid grid-cols-1 gap-6">
             <h2 className="text-xl font-bold text-gray-900">Grafik Kategori</h2>
-            {renderBarChart('Penerimaan per Kategori', incomeData)}
-            {renderBarChart('Pengeluaran per Kategori', expenseData)}
+            {renderBarChart('Penerimaan per Kategori', charts['income-mix'] || [])}
+            {renderBarChart('Pengeluaran per Kategori', charts['expense-mix'] || [])}
           </div>
 
           {/* Monthly, Yearly, and Running Balance Reports */}
@@ -912,10 +924,6 @@ const ReportsPage: React.FC = () => {
     );
   }
 
-  if (!reports) {
-    return null;
-  }
-
   return (
     <div className="min-h-screen bg-gray-50 py-8 px-4 sm:px-6 lg:px-8">
       <div className="max-w-7xl mx-auto">
@@ -925,6 +933,9 @@ const ReportsPage: React.FC = () => {
             {selectedAccount && (
               <p className="text-sm text-gray-500 mt-1">Akun: {selectedAccount}</p>
             )}
+            {reports?.figuresInThousands && (
+              <p className="text-sm text-gray-500">Angka dalam ribuan Rupiah</p>
+            )}
           </div>
           <div className="flex flex-col sm:flex-row gap-3 w-full sm:w-auto">
             <div className="w-full sm:w-64">
@@ -966,6 +977,30 @@ const ReportsPage: React.FC = () => {
                 </>
               )}
             </button>
+            <button
+              onClick={handlePrint}
+              disabled={!reports || isPrinting}
+              className="inline-flex justify-center items-center px-4 py-2 border border-gray-300 shadow-sm text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 disabled:opacity-70 disabled:cursor-not-allowed"
+            >
+              <PrinterIcon className="-ml-1 mr-2 h-5 w-5 text-gray-500" />
+              {isPrinting ? 'Menyiapkan...' : 'Cetak'}
+            </button>
+            <button
+              onClick={handleExportPackage}
+              disabled={!reports || isExportingPackage}
+              className="inline-flex justify-center items-center px-4 py-2 border border-gray-300 shadow-sm text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 disabled:opacity-70 disabled:cursor-not-allowed"
+            >
+              <TableCellsIcon className="-ml-1 mr-2 h-5 w-5 text-gray-500" />
+              {isExportingPackage ? 'Mengekspor...' : 'Paket Laporan'}
+            </button>
+            <button
+              onClick={handleExportPeriod}
+              disabled={!reports || isExportingPackage}
+              className="inline-flex justify-center items-center px-4 py-2 border border-gray-300 shadow-sm text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 disabled:opacity-70 disabled:cursor-not-allowed"
+            >
+              <ArchiveBoxArrowDownIcon className="-ml-1 mr-2 h-5 w-5 text-gray-500" />
+              Paket Periode
+            </button>
             <button
               onClick={() => navigate(-1)}
               className="inline-flex justify-center items-center px-4 py-2 border border-gray-300 shadow-sm text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500"
@@ -1001,6 +1036,31 @@ const ReportsPage: React.FC = () => {
                 />
               </div>
             </div>
+            <div className="flex flex-col mt-2">
+              <label htmlFor="display-units" className="text-xs text-gray-500 mb-1">Pembulatan</label>
+              <select
+                id="display-units"
+                value={units}
+                onChange={(e) => setUnits(e.target.value as DisplayUnits | '')}
+                className="border border-gray-300 rounded-md px-2 py-1 text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+              >
+                <option value="">Tanpa pembulatan</option>
+                <option value="unit">Rupiah penuh</option>
+                <option value="thousands">Ribuan (000)</option>
+              </select>
+            </div>
+            <div className="flex flex-col mt-2">
+              <label htmlFor="amount-presentation" className="text-xs text-gray-500 mb-1">Tampilan jumlah</label>
+              <select
+                id="amount-presentation"
+                value={amountPresentation}
+                onChange={(e) => setAmountPresentation(e.target.value as AmountPresentation)}
+                className="border border-gray-300 rounded-md px-2 py-1 text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+              >
+                <option value="split">Kolom penerimaan &amp; pengeluaran</option>
+                <option value="signed">Satu kolom bertanda (+/-)</option>
+              </select>
+            </div>
             <button
               type="button"
               onClick={() => {
@@ -1020,115 +1080,165 @@ const ReportsPage: React.FC = () => {
         </div>
 
         <div ref={reportRef} className="grid grid-cols-1 gap-6">
-          {/* Laporan Saldo */}
-          <div className="bg-white shadow overflow-hidden sm:rounded-lg">
-            <div className="px-4 py-5 sm:px-6 border-b border-gray-200">
-              <h3 className="text-lg leading-6 font-medium text-gray-900">Laporan Saldo</h3>
-              <p className="mt-1 max-w-2xl text-sm text-gray-500">Ringkasan penerimaan dan pengeluaran</p>
-            </div>
-            <div className="px-4 py-5 sm:p-6">
-              <div className="grid grid-cols-1 md:grid-cols-2 gap-8">
+          {/* Kesehatan Usaha */}
+          {insights && insights.score !== null && (
+            <div className="bg-white shadow overflow-hidden sm:rounded-lg">
+              <div className="px-4 py-5 sm:px-6 border-b border-gray-200 flex items-center justify-between">
                 <div>
-                  <h4 className="text-md font-medium text-gray-900 mb-4">Penerimaan</h4>
-                  {incomeData.length > 0 ? (
-                    <div className="space-y-2">
-                      {incomeData.map((item) => (
-                        <div key={item.name} className="flex justify-between">
-                          <span className="text-gray-600">{item.name}</span>
-                          <span className="font-medium">
-                            {formatCurrency(item.value)}
-                          </span>
+                  <h3 className="text-lg leading-6 font-medium text-gray-900">Kesehatan Usaha</h3>
+                  <p className="mt-1 max-w-2xl text-sm text-gray-500">Wawasan hingga {insights.asOf}</p>
+                </div>
+                <span
+                  className={`text-2xl font-bold ${
+                    insights.score >= 80 ? 'text-green-600' : insights.score >= 50 ? 'text-yellow-600' : 'text-red-600'
+                  }`}
+                >
+                  {insights.score}/100
+                </span>
+              </div>
+              <ul className="divide-y divide-gray-200">
+                {insights.insights.length === 0 && (
+                  <li className="px-4 py-3 sm:px-6 text-sm text-gray-500">Tidak ada hal yang perlu diperhatikan.</li>
+                )}
+                {insights.insights.map((insight, index) => (
+                  <li key={`${insight.key}-${index}`} className="px-4 py-3 sm:px-6">
+                    <p
+                      className={`text-sm font-medium ${
+                        insight.severity === 'warning'
+                          ? 'text-red-700'
+                          : insight.severity === 'positive'
+                            ? 'text-green-700'
+                            : 'text-gray-900'
+                      }`}
+                    >
+                      {insight.title}
+                    </p>
+                    <p className="text-sm text-gray-600">{insight.message}</p>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          )}
+
+          {!reports ? (
+            <div className="bg-white shadow sm:rounded-lg px-4 py-5 sm:p-6">
+              <p className={`text-sm ${reportError ? 'text-red-700' : 'text-gray-500'}`}>
+                {reportError || 'Memuat laporan...'}
+              </p>
+            </div>
+          ) : (
+            <>
+              {/* Laporan Saldo */}
+              <div className="bg-white shadow overflow-hidden sm:rounded-lg">
+                <div className="px-4 py-5 sm:px-6 border-b border-gray-200">
+                  <h3 className="text-lg leading-6 font-medium text-gray-900">Laporan Saldo</h3>
+                  <p className="mt-1 max-w-2xl text-sm text-gray-500">Ringkasan penerimaan dan pengeluaran</p>
+                </div>
+                <div className="px-4 py-5 sm:p-6">
+                  <div className="grid grid-cols-1 md:grid-cols-2 gap-8">
+                    <div>
+                      <h4 className="text-md font-medium text-gray-900 mb-4">Penerimaan</h4>
+                      {incomeData.length > 0 ? (
+                        <div className="space-y-2">
+                          {incomeData.map((item) => (
+                            <div key={item.name} className="flex justify-between">
+                              <span className="text-gray-600">{item.name}</span>
+                              <span className="font-medium">
+                                {formatCurrency(item.value)}
+                              </span>
+                            </div>
+                          ))}
                         </div>
-                      ))}
+                      ) : (
+                        <p className="text-sm text-gray-500">Tidak ada data penerimaan</p>
+                      )}
                     </div>
-                  ) : (
-                    <p className="text-sm text-gray-500">Tidak ada data penerimaan</p>
-                  )}
-                </div>
-                <div>
-                  <h4 className="text-md font-medium text-gray-900 mb-4">Pengeluaran</h4>
-                  {expenseData.length > 0 ? (
-                    <div className="space-y-2">
-                      {expenseData.map((item) => (
-                        <div key={item.name} className="flex justify-between">
-                          <span className="text-gray-600">{item.name}</span>
-                          <span className="font-medium text-red-600">
-                            - {formatCurrency(item.value)}
-                          </span>
+                    <div>
+                      <h4 className="text-md font-medium text-gray-900 mb-4">Pengeluaran</h4>
+                      {expenseData.length > 0 ? (
+                        <div className="space-y-2">
+                          {expenseData.map((item) => (
+                            <div key={item.name} className="flex justify-between">
+                              <span className="text-gray-600">{item.name}</span>
+                              <span className="font-medium text-red-600">
+                                {formatExpense(item.value)}
+                              </span>
+                            </div>
+                          ))}
                         </div>
-                      ))}
+                      ) : (
+                        <p className="text-sm text-gray-500">Tidak ada data pengeluaran</p>
+                      )}
                     </div>
-                  ) : (
-                    <p className="text-sm text-gray-500">Tidak ada data pengeluaran</p>
-                  )}
+                  </div>
+                  <div className="mt-8 pt-4 border-t border-gray-200">
+                    <div className="flex justify-between">
+                      <span className="text-lg font-medium">Saldo Akhir</span>
+                      <span className={`text-lg font-bold ${
+                        reports.closingBalance >= 0 ? 'text-green-600' : 'text-red-600'
+                      }`}>
+                        {formatCurrency(reports.closingBalance)}
+                      </span>
+                    </div>
+                  </div>
                 </div>
               </div>
-              <div className="mt-8 pt-4 border-t border-gray-200">
-                <div className="flex justify-between">
-                  <span className="text-lg font-medium">Saldo Akhir</span>
-                  <span className={`text-lg font-bold ${
-                    reports.income_statement.net_income >= 0 ? 'text-green-600' : 'text-red-600'
-                  }`}>
-                    {formatCurrency(reports.income_statement.net_income)}
-                  </span>
-                </div>
+              {/* Category Charts */}
+              <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
+                {renderBarChart('Penerimaan per Kategori', charts['income-mix'] || [])}
+                {renderBarChart('Pengeluaran per Kategori', charts['expense-mix'] || [])}
               </div>
-            </div>
-          </div>
-          {/* Category Charts */}
-          <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
-            {renderBarChart('Penerimaan per Kategori', incomeData)}
-            {renderBarChart('Pengeluaran per Kategori', expenseData)}
-          </div>
 
-          {!exportMode && (
-            <>
-              {/* Tabs */}
-              <div className="border-b border-gray-200">
-                <nav className="-mb-px flex space-x-8" aria-label="Tabs">
-                  <button
-                    onClick={() => setActiveTab('monthly')}
-                    className={`${activeTab === 'monthly' 
-                      ? 'border-blue-500 text-blue-600' 
-                      : 'border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300'
-                    } whitespace-nowrap py-4 px-1 border-b-2 font-medium text-sm`}
-                  >
-                    Bulanan
-                  </button>
-                  <button
-                    onClick={() => setActiveTab('yearly')}
-                    className={`${activeTab === 'yearly' 
-                      ? 'border-blue-500 text-blue-600' 
-                      : 'border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300'
-                    } whitespace-nowrap py-4 px-1 border-b-2 font-medium text-sm`}
-                  >
-                    Tahunan
-                  </button>
-                  <button
-                    onClick={() => setActiveTab('running')}
-                    className={`${activeTab === 'running' 
-                      ? 'border-blue-500 text-blue-600' 
-                      : 'border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300'
-                    } whitespace-nowrap py-4 px-1 border-b-2 font-medium text-sm`}
-                  >
-                    Saldo Berjalan
-                  </button>
-                </nav>
-              </div>
+              {!exportMode && (
+                <>
+                  {/* Tabs */}
+                  <div className="border-b border-gray-200">
+                    <nav className="-mb-px flex space-x-8" aria-label="Tabs">
+                      <button
+                        onClick={() => setActiveTab('monthly')}
+                        className={`${activeTab === 'monthly' 
+                          ? 'border-blue-500 text-blue-600' 
+                          : 'border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300'
+                        } whitespace-nowrap py-4 px-1 border-b-2 font-medium text-sm`}
+                      >
+                        Bulanan
+                      </button>
+                      <button
+                        onClick={() => setActiveTab('yearly')}
+                        className={`${activeTab === 'yearly' 
+                          ? 'border-blue-500 text-blue-600' 
+                          : 'border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300'
+                        } whitespace-nowrap py-4 px-1 border-b-2 font-medium text-sm`}
+                      >
+                        Tahunan
+                      </button>
+                      <button
+                        onClick={() => setActiveTab('running')}
+                        className={`${activeTab === 'running' 
+                          ? 'border-blue-500 text-blue-600' 
+                          : 'border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300'
+                        } whitespace-nowrap py-4 px-1 border-b-2 font-medium text-sm`}
+                      >
+                        Saldo Berjalan
+                      </button>
+                    </nav>
+                  </div>
 
-              {/* Tab Content */}
-              <div className="mt-6">
-                {renderReports()}
-              </div>
+                  {/* Tab Content */}
+                  <div className="mt-6">
+                    {renderReports()}
+                  </div>
+                </>
+              )}
+
+              {/* Export Mode Content */}
+              {exportMode && (
+                <div className="space-y-8">
+                  {renderReports()}
+                </div>
+              )}
             </>
           )}
-          
-          {/* Export Mode Content */}
-          {exportMode && (
-            <div className="space-y-8">
-              {renderReports()}
-            </div>
-          )}
         </div>
       </div>
     </div>
@@ -8,9 +8,13 @@ import { ExclamationTriangleIcon } from '@heroicons/react/24/outline';
 import { 
   CloudArrowUpIcon, 
   DocumentArrowDownIcon,
-  ArrowPathIcon
+  ArrowPathIcon,
+  ClipboardDocumentListIcon
 } from '@heroicons/react/24/outline';
 import { downloadTemplate } from '@/services/api';
+import { calculateBalance, getNetAmount, getTransactionTotals } from '@/utils/balance';
+import { parseClipboardTable, ClipboardImport, PastedColumnRole } from '@/utils/clipboardTable';
+import { BackupCompare } from '@/components/BackupCompare';
 
 interface AccountData {
   id: string;
@@ -31,12 +35,36 @@ interface FileValidationError {
 }
 
 // Constants
+const DEFAULT_PASTE_ACCOUNT = 'Tempelan';
+const PASTE_PREVIEW_ROWS = 10;
 const MAX_FILE_SIZE = 10 * 1024 * 1024; // 10MB
 const ALLOWED_FILE_TYPES = [
   'application/vnd.openxmlformats-officedocument.spreadsheetml.sheet',
   'application/vnd.ms-excel'
 ];
 
+// How each column role is described in the paste preview
+const PASTE_ROLE_LABELS: Record<PastedColumnRole, string> = {
+  tanggal: 'Tanggal',
+  uraian: 'Uraian',
+  penerimaan: 'Penerimaan',
+  pengeluaran: 'Pengeluaran',
+  jumlah: 'Jumlah (negatif = pengeluaran)',
+  saldo: 'Saldo berjalan (diabaikan)',
+  diabaikan: 'Diabaikan',
+};
+
+// Amounts in the paste preview keep their decimals so misread separators show
+const formatPreviewAmount = (amount: number): string =>
+  amount
+    ? new Intl.NumberFormat('id-ID', {
+        style: 'currency',
+        currency: 'IDR',
+        minimumFractionDigits: 0,
+        maximumFractionDigits: 2
+      }).format(amount)
+    : '';
+
 // Helper function to check for duplicate transactions
 const checkForDuplicates = (transactions: TransactionItem[]): TransactionItem[] => {
   const duplicates: TransactionItem[] = [];
@@ -73,6 +101,9 @@ const UploadPage = () => {
     items: [],
     onConfirm: null
   });
+  const [pastedText, setPastedText] = useState('');
+  const [pasteAccountName, setPasteAccountName] = useState('');
+  const [pastePreview, setPastePreview] = useState<ClipboardImport | null>(null);
   const fileInputRef = useRef<HTMLInputElement>(null);
   const { dispatch, state } = useAppContext();
   const navigate = useNavigate();
@@ -132,11 +163,7 @@ const UploadPage = () => {
         // Calculate jumlah if not provided
         let jumlah = typeof tx.jumlah === 'number' ? tx.jumlah : 0;
         if (jumlah === 0) {
-          const penerimaanTotal = Object.values(defaultPenerimaan).reduce((sum: number, val) => 
-            sum + (Number(val) || 0), 0);
-          const pengeluaranTotal = Object.values(defaultPengeluaran).reduce((sum: number, val) => 
-            sum + (Number(val) || 0), 0);
-          jumlah = penerimaanTotal - pengeluaranTotal;
+          jumlah = getNetAmount({ penerimaan: defaultPenerimaan, pengeluaran: defaultPengeluaran });
         }
         
         return {
@@ -297,17 +324,13 @@ const UploadPage = () => {
             }
           }
           
-          // Calculate total income and expense
-          const totalPenerimaan = Object.values(penerimaan).reduce((sum: number, val) => sum + val, 0);
-          const totalPengeluaran = Object.values(pengeluaran).reduce((sum: number, val) => sum + val, 0);
-          
           // Get transaction amount (use 0 if not a number)
           const amount = typeof row[jumlahCol] === 'number' ? row[jumlahCol] : 0;
           
           // Get or calculate balance
           let saldo = getNumberValue(jumlahCol);
           if (saldo === 0) {
-            saldo = totalPenerimaan - totalPengeluaran;
+            saldo = getNetAmount({ penerimaan, pengeluaran });
             runningBalance += saldo;
           } else {
             runningBalance = saldo;
@@ -550,6 +573,50 @@ const UploadPage = () => {
     }
   };
 
+  // Read the pasted rows and show how they were understood before importing them
+  const handlePastePreview = () => {
+    try {
+      setPastePreview(parseClipboardTable(pastedText));
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : 'Data yang ditempel tidak dapat diproses';
+      toast.error(errorMessage);
+      setPastePreview(null);
+    }
+  };
+
+  // Add the previewed rows to an account, keeping the other accounts
+  const handlePasteImport = () => {
+    if (!pastePreview) return;
+    const { transactions } = pastePreview;
+
+    const accountName = pasteAccountName.trim() || DEFAULT_PASTE_ACCOUNT;
+    const existing = state.accounts.find(acc => acc.name === accountName);
+    const merged = [...(existing?.transactions || []), ...transactions];
+
+    const applyPaste = () => {
+      if (existing) {
+        dispatch({ type: 'UPDATE_ACCOUNT', payload: { accountName, transactions: merged } });
+        dispatch({ type: 'SET_CURRENT_ACCOUNT', payload: accountName });
+      } else {
+        dispatch({
+          type: 'ADD_ACCOUNT',
+          payload: { name: accountName, transactions, balance: calculateBalance(transactions) }
+        });
+      }
+      setPastedText('');
+      setPastePreview(null);
+      toast.success(`${transactions.length} transaksi ditambahkan ke akun ${accountName}`);
+      navigate('/editor');
+    };
+
+    const duplicateItems = checkForDuplicates(merged);
+    if (duplicateItems.length > 0) {
+      setDuplicates({ isOpen: true, items: duplicateItems, onConfirm: applyPaste });
+    } else {
+      applyPaste();
+    }
+  };
+
   const handleDragOver = (e: DragEvent<HTMLDivElement>) => {
     e.preventDefault();
     e.stopPropagation();
@@ -790,6 +857,116 @@ const UploadPage = () => {
           </button>
         </div>
         
+        {/* Paste from spreadsheet */}
+        <div className="mt-8 bg-white shadow rounded-lg p-6">
+          <h3 className="text-lg font-medium text-gray-900 mb-1">Tempel dari Spreadsheet</h3>
+          <p className="text-sm text-gray-600 mb-4">
+            Salin baris transaksi dari Excel atau Google Sheets lalu tempel di sini, tanpa perlu menyimpan file
+          </p>
+          <textarea
+            value={pastedText}
+            onChange={(e) => {
+              setPastedText(e.target.value);
+              setPastePreview(null);
+            }}
+            rows={6}
+            placeholder={'Tanggal\tUraian\tPenerimaan_Penjualan\tPengeluaran_Gaji\n15/05/2025\tPenjualan tunai\t1.250.000\t'}
+            className="w-full rounded-md border border-gray-300 px-3 py-2 font-mono text-xs focus:outline-none focus:ring-2 focus:ring-blue-500"
+            disabled={isProcessing}
+          />
+          <div className="mt-3 flex flex-col sm:flex-row gap-3">
+            <input
+              type="text"
+              value={pasteAccountName}
+              onChange={(e) => setPasteAccountName(e.target.value)}
+              placeholder={`Nama akun (default: ${DEFAULT_PASTE_ACCOUNT})`}
+              list="paste-account-names"
+              className="flex-1 rounded-md border border-gray-300 px-3 py-2 text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+              disabled={isProcessing}
+            />
+            <datalist id="paste-account-names">
+              {state.accounts.map(acc => (
+                <option key={acc.name} value={acc.name} />
+              ))}
+            </datalist>
+            <button
+              type="button"
+              onClick={handlePastePreview}
+              className="inline-flex items-center justify-center px-4 py-2 border border-transparent text-sm font-medium rounded-md shadow-sm text-white bg-blue-600 hover:bg-blue-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed"
+              disabled={!pastedText.trim() || isProcessing}
+            >
+              <ClipboardDocumentListIcon className="-ml-1 mr-2 h-4 w-4" />
+              Pratinjau Tempelan
+            </button>
+          </div>
+
+          {pastePreview && (
+            <div className="mt-4 border-t border-gray-200 pt-4">
+              <h4 className="text-sm font-medium text-gray-900 mb-2">
+                Cara kolom dibaca {pastePreview.hasHeader ? '(dari baris judul)' : '(ditebak dari isi)'}
+              </h4>
+              <ul className="mb-4 grid grid-cols-1 sm:grid-cols-2 gap-1 text-sm text-gray-600">
+                {pastePreview.columns.map(column => (
+                  <li key={column.index}>
+                    <span className="font-medium text-gray-800">{column.label}</span>
+                    {' → '}
+                    {PASTE_ROLE_LABELS[column.role]}
+                    {column.category && ` · kategori ${column.category}`}
+                  </li>
+                ))}
+              </ul>
+              <div className="overflow-x-auto">
+                <table className="min-w-full divide-y divide-gray-200 text-sm">
+                  <thead className="bg-gray-50">
+                    <tr>
+                      <th className="px-3 py-2 text-left font-medium text-gray-500">Tanggal</th>
+                      <th className="px-3 py-2 text-left font-medium text-gray-500">Uraian</th>
+                      <th className="px-3 py-2 text-right font-medium text-gray-500">Penerimaan</th>
+                      <th className="px-3 py-2 text-right font-medium text-gray-500">Pengeluaran</th>
+                    </tr>
+                  </thead>
+                  <tbody className="divide-y divide-gray-200">
+                    {pastePreview.transactions.slice(0, PASTE_PREVIEW_ROWS).map(tx => {
+                      const { income, expense } = getTransactionTotals(tx);
+                      return (
+                        <tr key={tx.id}>
+                          <td className="px-3 py-2 whitespace-nowrap text-gray-900">{tx.tanggal}</td>
+                          <td className="px-3 py-2 text-gray-900">{tx.uraian}</td>
+                          <td className="px-3 py-2 text-right text-green-700">{formatPreviewAmount(income)}</td>
+                          <td className="px-3 py-2 text-right text-red-700">{formatPreviewAmount(expense)}</td>
+                        </tr>
+                      );
+                    })}
+                  </tbody>
+                </table>
+              </div>
+              {pastePreview.transactions.length > PASTE_PREVIEW_ROWS && (
+                <p className="mt-2 text-xs text-gray-500">
+                  dan {pastePreview.transactions.length - PASTE_PREVIEW_ROWS} transaksi lainnya
+                </p>
+              )}
+              <div className="mt-4 flex justify-end gap-3">
+                <button
+                  type="button"
+                  onClick={() => setPastePreview(null)}
+                  className="px-4 py-2 border border-gray-300 text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500"
+                >
+                  Batal
+                </button>
+                <button
+                  type="button"
+                  onClick={handlePasteImport}
+                  className="px-4 py-2 border border-transparent text-sm font-medium rounded-md shadow-sm text-white bg-blue-600 hover:bg-blue-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500"
+                >
+                  Tambahkan {pastePreview.transactions.length} Transaksi
+                </button>
+              </div>
+            </div>
+          )}
+        </div>
+
+        <BackupCompare />
+
         {/* Quick Help */}
         <div className="mt-8 bg-white shadow rounded-lg p-6">
           <h3 className="text-lg font-medium text-gray-900 mb-4">Panduan Singkat</h3>
@@ -810,6 +987,10 @@ const UploadPage = () => {
               <span className="text-blue-500 mr-2">•</span>
               <span>Gunakan koma (,) untuk desimal dan titik (.) untuk ribuan</span>
             </li>
+            <li className="flex items-start">
+              <span className="text-blue-500 mr-2">•</span>
+              <span>Data yang ditempel tanpa baris judul dibaca otomatis: kolom tanggal, uraian, lalu satu kolom jumlah (negatif = pengeluaran) atau dua kolom penerimaan dan pengeluaran; kolom saldo berjalan di akhir dikenali dan diabaikan. Periksa pratinjau sebelum menambahkan</span>
+            </li>
           </ul>
         </div>
       </div>
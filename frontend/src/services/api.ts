@@ -1,4 +1,5 @@
 import axios from 'axios';
+import type { AmountPresentation } from '@/utils/balance';
 
 // Get the current hostname to determine the environment
 const getApiBaseUrl = () => {
@@ -258,3 +259,454 @@ export const exportToExcel = async (accounts: Array<{ name: string; transactions
     throw new Error(`Failed to export to Excel: ${errorMessage}`);
   }
 };
+
+export type ReportType = 'summary' | 'monthly' | 'yearly' | 'running' | 'categories';
+
+// Rounding applied by the backend: whole Rupiah, or thousands ("figures in 000s")
+export type DisplayUnits = 'unit' | 'thousands';
+
+export interface ReportParams {
+  account?: string | null;
+  startDate?: string;
+  endDate?: string;
+  units?: DisplayUnits;
+  presentation?: AmountPresentation;
+}
+
+/**
+ * Render a print-ready HTML report on the server
+ * @param report Report type to render
+ * @param accounts Array of account data to report on
+ * @param params Account and date range the report covers
+ */
+export const renderReportHtml = async (
+  report: ReportType,
+  accounts: Array<{ name: string; transactions: any[] }>,
+  params: ReportParams = {}
+): Promise<string> => {
+  try {
+    const response = await apiClient.post<string>(
+      '/api/reports/html',
+      { report, accounts, params },
+      {
+        responseType: 'text',
+        headers: {
+          'Content-Type': 'application/json',
+          'Accept': 'text/html',
+        },
+      }
+    );
+
+    if (!response.data) {
+      throw new Error('No data received from server');
+    }
+
+    return response.data;
+  } catch (error) {
+    console.error('Error rendering report:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to render report: ${errorMessage}`);
+  }
+};
+
+export interface ReportPeriodRow {
+  period: string; // YYYY-MM or YYYY
+  label: string;
+  income: number;
+  expense: number;
+  net: number;
+}
+
+export interface ReportRunningRow {
+  date: string;
+  account: string;
+  uraian: string;
+  income: number;
+  expense: number;
+  net: number;
+  runningBalance: number;
+}
+
+export interface ReportData {
+  accounts: string[];
+  startDate: string | null;
+  endDate: string | null;
+  openingBalance: number;
+  closingBalance: number;
+  totals: { income: number; expense: number; net: number };
+  monthly: ReportPeriodRow[];
+  yearly: ReportPeriodRow[];
+  running: ReportRunningRow[];
+  incomeByCategory: Record<string, number>;
+  expenseByCategory: Record<string, number>;
+  units: DisplayUnits | null;
+  figuresInThousands: boolean;
+  charts: ChartSeries;
+}
+
+/**
+ * Get the report figures computed (and rounded) by the backend, with every
+ * chart series of the same report
+ * @param accounts Array of account data to report on
+ * @param params Account, date range and rounding the report covers
+ */
+export const getReport = async (
+  accounts: Array<{ name: string; transactions: any[] }>,
+  params: ReportParams = {}
+): Promise<ReportData> => {
+  try {
+    const response = await apiClient.post('/api/reports/data', { accounts, params });
+
+    const data = response.data;
+    return {
+      accounts: data.accounts,
+      startDate: data.start_date,
+      endDate: data.end_date,
+      openingBalance: data.opening_balance,
+      closingBalance: data.closing_balance,
+      totals: data.totals,
+      monthly: data.monthly,
+      yearly: data.yearly,
+      running: data.running.map((row: any) => ({
+        date: row.date,
+        account: row.account,
+        uraian: row.uraian,
+        income: row.income,
+        expense: row.expense,
+        net: row.net,
+        runningBalance: row.running_balance,
+      })),
+      incomeByCategory: data.income_by_category,
+      expenseByCategory: data.expense_by_category,
+      units: data.units,
+      figuresInThousands: data.figures_in_thousands,
+      charts: Object.fromEntries(
+        Object.entries(data.charts).map(([chart, series]) => [chart, toChartPoints(series as any[])])
+      ) as unknown as ChartSeries,
+    };
+  } catch (error) {
+    console.error('Error getting report:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to get report: ${errorMessage}`);
+  }
+};
+
+export interface ExportFilter extends ReportParams {
+  entryType?: 'penerimaan' | 'pengeluaran';
+  search?: string;
+}
+
+/**
+ * Export the transactions matching a filter as a zip of CSV files
+ * @param accounts Array of account data to export from
+ * @param filter Account, date range, entry type and search text to match
+ */
+export const exportWorkingPaper = async (
+  accounts: Array<{ name: string; transactions: any[] }>,
+  filter: ExportFilter = {}
+): Promise<Blob> => {
+  try {
+    const response = await apiClient.post<Blob>(
+      '/api/export/working-paper',
+      { accounts, filter },
+      {
+        responseType: 'blob',
+        headers: {
+          'Content-Type': 'application/json',
+          'Accept': 'application/zip',
+        },
+      }
+    );
+
+    if (!response.data) {
+      throw new Error('No data received from server');
+    }
+
+    return response.data;
+  } catch (error) {
+    console.error('Error exporting working paper:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to export working paper: ${errorMessage}`);
+  }
+};
+
+/**
+ * Export the year-end statement package as a multi-sheet Excel workbook
+ * @param accounts Array of account data to build the statements from
+ * @param params Account and period the statements cover
+ */
+export const exportFinancialPackage = async (
+  accounts: Array<{ name: string; transactions: any[] }>,
+  params: ReportParams = {}
+): Promise<Blob> => {
+  try {
+    const response = await apiClient.post<Blob>(
+      '/api/export/financial-package',
+      { accounts, params },
+      {
+        responseType: 'blob',
+        headers: {
+          'Content-Type': 'application/json',
+          'Accept': 'application/vnd.openxmlformats-officedocument.spreadsheetml.sheet',
+        },
+      }
+    );
+
+    if (!response.data) {
+      throw new Error('No data received from server');
+    }
+
+    return response.data;
+  } catch (error) {
+    console.error('Error exporting financial package:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to export financial package: ${errorMessage}`);
+  }
+};
+
+/**
+ * Export one fiscal period as a zip of CSV files for the accountant
+ * @param accounts Array of account data to export from
+ * @param period Month (YYYY-MM) or year (YYYY)
+ * @param account Optional account to limit the package to
+ * @param presentation Split or signed amount columns
+ */
+export const exportPeriodPackage = async (
+  accounts: Array<{ name: string; transactions: any[] }>,
+  period: string,
+  account?: string | null,
+  presentation?: AmountPresentation
+): Promise<Blob> => {
+  try {
+    const response = await apiClient.post<Blob>(
+      '/api/export/period-package',
+      { accounts, period, account, presentation },
+      {
+        responseType: 'blob',
+        headers: {
+          'Content-Type': 'application/json',
+          'Accept': 'application/zip',
+        },
+      }
+    );
+
+    if (!response.data) {
+      throw new Error('No data received from server');
+    }
+
+    return response.data;
+  } catch (error) {
+    console.error('Error exporting period package:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to export period package: ${errorMessage}`);
+  }
+};
+
+export interface ReportComparisonRow {
+  key: string;
+  label: string;
+  type?: 'penerimaan' | 'pengeluaran';
+  a: number;
+  b: number;
+  delta: number;
+  deltaPercent: number | null;
+}
+
+export interface ReportComparison {
+  report: 'summary' | 'categories';
+  rows: ReportComparisonRow[];
+}
+
+/**
+ * Run the same report for two parameter sets and get aligned rows with deltas (B - A).
+ * Not used by any page yet; both parameter sets must use the same units.
+ * @param report Report type to compare
+ * @param accounts Array of account data to report on
+ * @param paramsA Account and date range for the first report
+ * @param paramsB Account and date range for the second report
+ * @param accountsB Optional second data set, e.g. an edited copy of the accounts
+ */
+export const compareReports = async (
+  report: ReportComparison['report'],
+  accounts: Array<{ name: string; transactions: any[] }>,
+  paramsA: ReportParams,
+  paramsB: ReportParams,
+  accountsB?: Array<{ name: string; transactions: any[] }>
+): Promise<ReportComparison> => {
+  try {
+    const response = await apiClient.post(
+      '/api/reports/compare',
+      { report, accounts, paramsA, paramsB, accountsB }
+    );
+
+    return {
+      report: response.data.report,
+      rows: response.data.rows.map((row: any) => ({
+        key: row.key,
+        label: row.label,
+        type: row.type,
+        a: row.a,
+        b: row.b,
+        delta: row.delta,
+        deltaPercent: row.delta_percent,
+      })),
+    };
+  } catch (error) {
+    console.error('Error comparing reports:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to compare reports: ${errorMessage}`);
+  }
+};
+
+export interface BackupTransaction {
+  tanggal: string;
+  uraian: string;
+  penerimaan: Record<string, number>;
+  pengeluaran: Record<string, number>;
+}
+
+export interface BackupAccountDiff {
+  name: string;
+  balanceA: number;
+  balanceB: number;
+  balanceDelta: number;
+  added: BackupTransaction[];
+  removed: BackupTransaction[];
+  changed: Array<{ before: BackupTransaction; after: BackupTransaction }>;
+}
+
+export interface BackupComparison {
+  accountsAdded: Array<{ name: string; balance: number; transactions: number }>;
+  accountsRemoved: Array<{ name: string; balance: number; transactions: number }>;
+  accountsChanged: BackupAccountDiff[];
+  summary: {
+    transactionsAdded: number;
+    transactionsRemoved: number;
+    transactionsChanged: number;
+    balanceA: number;
+    balanceB: number;
+  };
+}
+
+/**
+ * Compare two exported Excel backups and list what changed from A to B
+ * @param fileA Older backup
+ * @param fileB Newer backup
+ */
+export const compareBackups = async (fileA: File, fileB: File): Promise<BackupComparison> => {
+  try {
+    const formData = new FormData();
+    formData.append('file_a', fileA);
+    formData.append('file_b', fileB);
+
+    const response = await apiClient.post('/api/backups/compare', formData, {
+      headers: {
+        'Content-Type': 'multipart/form-data',
+      },
+    });
+
+    const data = response.data;
+    return {
+      accountsAdded: data.accounts_added,
+      accountsRemoved: data.accounts_removed,
+      accountsChanged: data.accounts_changed.map((account: any) => ({
+        name: account.name,
+        balanceA: account.balance_a,
+        balanceB: account.balance_b,
+        balanceDelta: account.balance_delta,
+        added: account.added,
+        removed: account.removed,
+        changed: account.changed,
+      })),
+      summary: {
+        transactionsAdded: data.summary.transactions_added,
+        transactionsRemoved: data.summary.transactions_removed,
+        transactionsChanged: data.summary.transactions_changed,
+        balanceA: data.summary.balance_a,
+        balanceB: data.summary.balance_b,
+      },
+    };
+  } catch (error) {
+    console.error('Error comparing backups:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to compare backups: ${errorMessage}`);
+  }
+};
+
+export interface Insight {
+  key: 'revenue_trend' | 'expense_spike' | 'concentration' | 'cash_runway';
+  severity: 'warning' | 'info' | 'positive';
+  title: string;
+  message: string;
+  figures: Record<string, string | number>;
+}
+
+export interface InsightsResult {
+  score: number | null;
+  asOf: string | null;
+  insights: Insight[];
+}
+
+/**
+ * Get the business health score and ranked insights
+ * @param accounts Array of account data to analyse
+ * @param account Optional account to limit the insights to
+ */
+export const getInsights = async (
+  accounts: Array<{ name: string; transactions: any[] }>,
+  account?: string | null
+): Promise<InsightsResult> => {
+  try {
+    const response = await apiClient.post('/api/insights', { accounts, account });
+
+    return {
+      score: response.data.score,
+      asOf: response.data.as_of,
+      insights: response.data.insights,
+    };
+  } catch (error) {
+    console.error('Error getting insights:', error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to get insights: ${errorMessage}`);
+  }
+};
+
+export interface ChartSeries {
+  monthly: Array<{ period: string; label: string; income: number; expense: number; net: number }>;
+  yearly: Array<{ period: string; label: string; income: number; expense: number; net: number }>;
+  balance: Array<{ date: string; balance: number }>;
+  'income-mix': Array<{ name: string; value: number; sharePercent: number }>;
+  'expense-mix': Array<{ name: string; value: number; sharePercent: number }>;
+}
+
+export type ChartType = keyof ChartSeries;
+
+// Map chart points from the backend, renaming share_percent in the mix series
+const toChartPoints = (series: any[]) =>
+  series.map((point: any) => {
+    const { share_percent, ...rest } = point;
+    return share_percent === undefined ? rest : { ...rest, sharePercent: share_percent };
+  });
+
+/**
+ * Get a pre-aggregated chart series computed by the backend.
+ * The reports page takes all series from getReport in one request instead.
+ * @param chart Chart to get the series for
+ * @param accounts Array of account data to aggregate
+ * @param params Account, date range and rounding the series covers
+ */
+export const getChartSeries = async <T extends ChartType>(
+  chart: T,
+  accounts: Array<{ name: string; transactions: any[] }>,
+  params: ReportParams = {}
+): Promise<ChartSeries[T]> => {
+  try {
+    const response = await apiClient.post(`/api/charts/${chart}`, { accounts, params });
+
+    return toChartPoints(response.data.series) as ChartSeries[T];
+  } catch (error) {
+    console.error(`Error getting ${chart} chart:`, error);
+    const errorMessage = error instanceof Error ? error.message : 'Unknown error occurred';
+    throw new Error(`Failed to get chart series: ${errorMessage}`);
+  }
+};
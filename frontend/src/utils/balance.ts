@@ -0,0 +1,67 @@
+import type { TransactionItem } from '@/types';
+
+/**
+ * Sign conventions for transaction amounts.
+ *
+ * Penerimaan (income) increases an account balance and pengeluaran (expense)
+ * decreases it. Balance and report calculations should use these helpers
+ * instead of re-implementing the rule.
+ */
+export type EntryType = 'penerimaan' | 'pengeluaran';
+
+type CategoryAmounts = Pick<TransactionItem, 'penerimaan' | 'pengeluaran'>;
+
+// Direction in which each entry type moves the balance
+export const NORMAL_SIGN: Record<EntryType, 1 | -1> = {
+  penerimaan: 1,
+  pengeluaran: -1,
+};
+
+// Sum all category amounts of one entry type, ignoring non-numeric values
+export const sumCategories = (amounts?: Record<string, number>): number =>
+  Object.values(amounts || {}).reduce((sum: number, val) => sum + (Number(val) || 0), 0);
+
+// Total income and expense of a transaction, both as positive numbers
+export const getTransactionTotals = (tx: Partial<CategoryAmounts>) => ({
+  income: sumCategories(tx.penerimaan),
+  expense: sumCategories(tx.pengeluaran),
+});
+
+// Net effect of income and expense totals on the balance (e.g. for a period)
+export const getNetFromTotals = ({ income, expense }: { income: number; expense: number }): number =>
+  NORMAL_SIGN.penerimaan * income + NORMAL_SIGN.pengeluaran * expense;
+
+// Net effect of a transaction on the account balance
+export const getNetAmount = (tx: Partial<CategoryAmounts>): number =>
+  getNetFromTotals(getTransactionTotals(tx));
+
+// How exports show amounts: separate penerimaan/pengeluaran columns, or one signed column
+export type AmountPresentation = 'split' | 'signed';
+
+// Signed amount for display: its effect on the balance, matching getNetAmount
+export const toPresentationAmount = (type: EntryType, amount: number): number =>
+  NORMAL_SIGN[type] * (Number(amount) || 0);
+
+// Final balance after applying all transactions
+export const calculateBalance = (
+  transactions: Partial<CategoryAmounts>[],
+  openingBalance: number = 0
+): number =>
+  transactions.reduce((balance, tx) => balance + getNetAmount(tx), openingBalance);
+
+// Recalculate jumlah and saldo_berjalan for transactions in their current order
+export const withRunningBalance = <T extends CategoryAmounts>(
+  transactions: T[],
+  openingBalance: number = 0
+): (T & { jumlah: number; saldo_berjalan: number })[] => {
+  let runningBalance = openingBalance;
+  return transactions.map(tx => {
+    const jumlah = getNetAmount(tx);
+    runningBalance += jumlah;
+    return {
+      ...tx,
+      jumlah,
+      saldo_berjalan: runningBalance,
+    };
+  });
+};
@@ -0,0 +1,84 @@
+import type { Category } from '@/contexts/AppContext';
+
+/**
+ * Portable list of accounts and categories ("bagan akun").
+ *
+ * Lets an accountant keep one master list of accounts and categories and
+ * load it into the app for each client, without copying transactions.
+ */
+export const CATEGORY_CHART_VERSION = 1;
+
+export interface CategoryChartEntry {
+  name: string;
+  type: Category['type'];
+  description?: string;
+}
+
+export interface CategoryChart {
+  version: number;
+  exportedAt: string;
+  accounts: string[];
+  categories: CategoryChartEntry[];
+}
+
+// Build the chart from the current accounts and categories
+export const buildCategoryChart = (
+  accountNames: string[],
+  categories: CategoryChartEntry[]
+): CategoryChart => ({
+  version: CATEGORY_CHART_VERSION,
+  exportedAt: new Date().toISOString(),
+  accounts: [...accountNames],
+  categories: categories.map(({ name, type, description }) => ({
+    name,
+    type,
+    ...(description ? { description } : {}),
+  })),
+});
+
+// Parse and validate a chart file, throwing an Indonesian error message if invalid
+export const parseCategoryChart = (text: string): CategoryChart => {
+  let data: any;
+  try {
+    data = JSON.parse(text);
+  } catch {
+    throw new Error('File bukan JSON yang valid');
+  }
+
+  if (!data || typeof data !== 'object' || !Array.isArray(data.categories)) {
+    throw new Error('Format bagan akun tidak dikenali');
+  }
+  if (data.version !== CATEGORY_CHART_VERSION) {
+    throw new Error(`Versi bagan akun tidak didukung: ${data.version}`);
+  }
+
+  const accounts: string[] = Array.isArray(data.accounts)
+    ? data.accounts
+        .filter((name: unknown) => typeof name === 'string' && name.trim())
+        .map((name: string) => name.trim())
+    : [];
+
+  const categories: CategoryChartEntry[] = data.categories.map((entry: any, index: number) => {
+    const name = typeof entry?.name === 'string' ? entry.name.trim() : '';
+    if (!name) {
+      throw new Error(`Kategori ke-${index + 1} tidak memiliki nama`);
+    }
+    if (entry.type !== 'penerimaan' && entry.type !== 'pengeluaran') {
+      throw new Error(`Jenis kategori "${name}" harus "penerimaan" atau "pengeluaran"`);
+    }
+    return {
+      name,
+      type: entry.type,
+      ...(typeof entry.description === 'string' && entry.description
+        ? { description: entry.description }
+        : {}),
+    };
+  });
+
+  return {
+    version: data.version,
+    exportedAt: typeof data.exportedAt === 'string' ? data.exportedAt : '',
+    accounts,
+    categories,
+  };
+};
@@ -0,0 +1,341 @@
+import type { TransactionItem } from '@/types';
+import { getNetAmount } from '@/utils/balance';
+
+/**
+ * Parse a block of rows copied from a spreadsheet into staged transactions.
+ *
+ * Columns are taken from a header row when present (same names as the Excel
+ * template: Tanggal, Uraian, Penerimaan_*, Pengeluaran_*, or one signed
+ * Jumlah column). Without a header, the date, description and amount columns
+ * are inferred from the values: a trailing running balance column is
+ * recognised and ignored, one amount column is read as signed (negative =
+ * pengeluaran), two amount columns as penerimaan and pengeluaran.
+ *
+ * The result carries the column mapping so it can be shown for review
+ * before the rows are added to an account.
+ */
+
+// Category used when the pasted columns do not name one
+export const DEFAULT_PASTE_CATEGORY = 'Umum';
+
+const INDONESIAN_MONTHS: Record<string, number> = {
+  januari: 1, februari: 2, maret: 3, april: 4, mei: 5, juni: 6,
+  juli: 7, agustus: 8, september: 9, oktober: 10, november: 11, desember: 12,
+};
+
+const pad = (value: number): string => String(value).padStart(2, '0');
+
+const toIsoDate = (year: number, month: number, day: number): string | null => {
+  const date = new Date(Date.UTC(year, month - 1, day));
+  if (
+    isNaN(date.getTime()) ||
+    date.getUTCFullYear() !== year ||
+    date.getUTCMonth() !== month - 1 ||
+    date.getUTCDate() !== day
+  ) {
+    return null;
+  }
+  return `${year}-${pad(month)}-${pad(day)}`;
+};
+
+// Dates as YYYY-MM-DD, DD/MM/YYYY (also with - or .) or "15 Mei 2025"
+export const parsePastedDate = (value: string): string | null => {
+  const text = value.trim();
+  let match = text.match(/^(\d{4})[-/.](\d{1,2})[-/.](\d{1,2})$/);
+  if (match) return toIsoDate(Number(match[1]), Number(match[2]), Number(match[3]));
+
+  match = text.match(/^(\d{1,2})[-/.](\d{1,2})[-/.](\d{4})$/);
+  if (match) return toIsoDate(Number(match[3]), Number(match[2]), Number(match[1]));
+
+  match = text.match(/^(\d{1,2})\s+([a-zA-Z]+)\s+(\d{4})$/);
+  if (match) {
+    const month = INDONESIAN_MONTHS[match[2].toLowerCase()];
+    return month ? toIsoDate(Number(match[3]), month, Number(match[1])) : null;
+  }
+  return null;
+};
+
+// Amounts in Indonesian (1.250.000,50) or plain (1250000.50) notation, optionally with Rp.
+// As in the template, a lone comma is the decimal separator: "12,500" is 12.5
+export const parsePastedAmount = (value: string): number | null => {
+  let text = value.trim().replace(/^rp\.?/i, '').replace(/\s/g, '');
+  let sign = 1;
+  if (/^\(.*\)$/.test(text)) {
+    sign = -1;
+    text = text.slice(1, -1);
+  }
+  if (text.startsWith('-')) {
+    sign = -sign;
+    text = text.slice(1);
+  }
+  text = text.replace(/^rp\.?/i, '');
+  if (!/^\d[\d.,]*$/.test(text)) return null;
+
+  const lastDot = text.lastIndexOf('.');
+  const lastComma = text.lastIndexOf(',');
+  if (lastDot !== -1 && lastComma !== -1) {
+    // Whichever separator comes last is the decimal one
+    text = lastComma > lastDot
+      ? text.replace(/\./g, '').replace(',', '.')
+      : text.replace(/,/g, '');
+  } else if (lastComma !== -1) {
+    // Several commas can only be thousand separators
+    text = text.indexOf(',') === lastComma ? text.replace(',', '.') : text.replace(/,/g, '');
+  } else if (/^\d{1,3}(\.\d{3})+$/.test(text)) {
+    // Dots in groups of three are thousand separators
+    text = text.replace(/\./g, '');
+  }
+
+  const amount = Number(text);
+  return isNaN(amount) ? null : sign * amount;
+};
+
+const detectDelimiter = (firstLine: string): string => {
+  if (firstLine.includes('\t')) return '\t';
+  if (firstLine.includes(';')) return ';';
+  return ',';
+};
+
+// Split one line, honouring double-quoted cells as copied from CSV
+const splitLine = (line: string, delimiter: string): string[] => {
+  const cells: string[] = [];
+  let cell = '';
+  let quoted = false;
+  for (let i = 0; i < line.length; i++) {
+    const char = line[i];
+    if (quoted) {
+      if (char === '"' && line[i + 1] === '"') {
+        cell += '"';
+        i++;
+      } else if (char === '"') {
+        quoted = false;
+      } else {
+        cell += char;
+      }
+    } else if (char === '"' && cell.trim() === '') {
+      quoted = true;
+      cell = '';
+    } else if (char === delimiter) {
+      cells.push(cell.trim());
+      cell = '';
+    } else {
+      cell += char;
+    }
+  }
+  cells.push(cell.trim());
+  return cells;
+};
+
+interface AmountColumn {
+  index: number;
+  // Fixed entry type and category, or signed when type is not given
+  type?: 'penerimaan' | 'pengeluaran';
+  category: string;
+}
+
+interface ColumnLayout {
+  dateCol: number;
+  descCol: number;
+  amounts: AmountColumn[];
+  // Running balance column, read for detection only (-1 when absent)
+  balanceCol: number;
+}
+
+export type PastedColumnRole = 'tanggal' | 'uraian' | 'penerimaan' | 'pengeluaran' | 'jumlah' | 'saldo' | 'diabaikan';
+
+// How one pasted column is read, for the import preview
+export interface PastedColumn {
+  index: number;
+  label: string;
+  role: PastedColumnRole;
+  category?: string;
+}
+
+export interface ClipboardImport {
+  transactions: TransactionItem[];
+  columns: PastedColumn[];
+  hasHeader: boolean;
+}
+
+const layoutFromHeader = (headers: string[]): ColumnLayout | null => {
+  const lower = headers.map(h => h.toLowerCase());
+  const dateCol = lower.indexOf('tanggal');
+  if (dateCol === -1) return null;
+
+  const amounts: AmountColumn[] = [];
+  headers.forEach((header, index) => {
+    const name = lower[index];
+    for (const type of ['penerimaan', 'pengeluaran'] as const) {
+      if (name === type) {
+        amounts.push({ index, type, category: DEFAULT_PASTE_CATEGORY });
+      } else if (name.startsWith(`${type}_`)) {
+        // Preserve the original category name case after the prefix
+        amounts.push({ index, type, category: header.substring(header.indexOf('_') + 1) });
+      }
+    }
+  });
+  if (amounts.length === 0) {
+    const jumlahCol = lower.indexOf('jumlah');
+    if (jumlahCol !== -1) amounts.push({ index: jumlahCol, category: DEFAULT_PASTE_CATEGORY });
+  }
+
+  return {
+    dateCol,
+    descCol: lower.indexOf('uraian'),
+    amounts,
+    balanceCol: lower.findIndex(name => name.startsWith('saldo')),
+  };
+};
+
+const amountAt = (row: string[], index: number): number => parsePastedAmount(row[index] || '') || 0;
+
+// Share of consecutive rows whose balance change equals the row's net amount.
+// Statements may list rows oldest or newest first, so both orders are tried.
+const balanceMatch = (rows: string[][], balanceCol: number, net: (row: string[]) => number): number => {
+  if (rows.length < 2) return 0;
+  const matches = (a: number, b: number) => Math.abs(a - b) < 0.005;
+  let forward = 0;
+  let backward = 0;
+  for (let i = 1; i < rows.length; i++) {
+    const change = amountAt(rows[i], balanceCol) - amountAt(rows[i - 1], balanceCol);
+    if (matches(change, net(rows[i]))) forward++;
+    if (matches(-change, net(rows[i - 1]))) backward++;
+  }
+  return Math.max(forward, backward) / (rows.length - 1);
+};
+
+const inferLayout = (rows: string[][]): ColumnLayout | null => {
+  const width = Math.max(...rows.map(row => row.length));
+  const share = (test: (value: string) => boolean, index: number): number => {
+    const values = rows.map(row => row[index] || '').filter(Boolean);
+    return values.length ? values.filter(test).length / values.length : 0;
+  };
+
+  const columns = Array.from({ length: width }, (_, index) => index);
+  const dateCol = columns.find(index => share(v => parsePastedDate(v) !== null, index) >= 0.8);
+  if (dateCol === undefined) return null;
+
+  const numeric = columns.filter(
+    index => index !== dateCol && share(v => parsePastedAmount(v) !== null, index) >= 0.8
+  );
+  const descCol = columns.find(index => index !== dateCol && !numeric.includes(index)) ?? -1;
+  const dated = rows.filter(row => parsePastedDate(row[dateCol] || '') !== null);
+  const hasNegative = (index: number) => dated.some(row => amountAt(row, index) < 0);
+  // Penerimaan and pengeluaran are rarely both filled on one row; an amount and its balance are
+  const filledTogether = (a: number, b: number) =>
+    dated.filter(row => row[a] && row[b]).length >= 0.8 * dated.length;
+
+  // Bank statements usually end with the running balance: it is the last
+  // column when its changes follow the amounts before it
+  let amountCols = numeric.slice(0, 2);
+  let balanceCol = -1;
+  let incomeFirst = true;
+  if (numeric.length >= 2) {
+    const last = numeric[numeric.length - 1];
+    const [first, second] = numeric;
+    if (numeric.length === 2) {
+      const signed = hasNegative(first) && filledTogether(first, last);
+      if (signed || balanceMatch(dated, last, row => amountAt(row, first)) >= 0.8) {
+        // A signed amount next to a balance, even when rows are missing in between
+        amountCols = [first];
+        balanceCol = last;
+      }
+    } else {
+      const inFirst = balanceMatch(dated, last, row => amountAt(row, first) - amountAt(row, second));
+      const outFirst = balanceMatch(dated, last, row => amountAt(row, second) - amountAt(row, first));
+      if (Math.max(inFirst, outFirst) >= 0.8) {
+        amountCols = [first, second];
+        balanceCol = last;
+        incomeFirst = inFirst >= outFirst;
+      }
+    }
+  }
+
+  const amounts: AmountColumn[] = amountCols.length === 1
+    ? [{ index: amountCols[0], category: DEFAULT_PASTE_CATEGORY }]
+    : amountCols.map((index, i): AmountColumn => ({
+        index,
+        type: (i === 0) === incomeFirst ? 'penerimaan' : 'pengeluaran',
+        category: DEFAULT_PASTE_CATEGORY,
+      }));
+
+  return { dateCol, descCol, amounts, balanceCol };
+};
+
+const describeColumns = (layout: ColumnLayout, width: number, headers: string[] | null): PastedColumn[] =>
+  Array.from({ length: width }, (_, index) => {
+    const label = headers?.[index] || `Kolom ${index + 1}`;
+    const amount = layout.amounts.find(column => column.index === index);
+    if (amount) {
+      return { index, label, role: amount.type ?? 'jumlah', category: amount.category };
+    }
+    let role: PastedColumnRole = 'diabaikan';
+    if (index === layout.dateCol) role = 'tanggal';
+    else if (index === layout.descCol) role = 'uraian';
+    else if (index === layout.balanceCol) role = 'saldo';
+    return { index, label, role };
+  });
+
+/**
+ * Parse pasted tab/comma/semicolon separated text into staged transactions,
+ * along with how each column was read.
+ * Throws an Indonesian error message if no transactions can be read.
+ */
+export const parseClipboardTable = (text: string): ClipboardImport => {
+  const lines = text.split(/\r?\n/).filter(line => line.trim());
+  if (lines.length === 0) {
+    throw new Error('Tidak ada data yang ditempel');
+  }
+
+  const delimiter = detectDelimiter(lines[0]);
+  let rows = lines.map(line => splitLine(line, delimiter));
+
+  const width = Math.max(...rows.map(row => row.length));
+  let layout = layoutFromHeader(rows[0]);
+  const headers = layout ? rows[0] : null;
+  if (layout) {
+    rows = rows.slice(1);
+  } else {
+    layout = inferLayout(rows);
+  }
+  if (!layout) {
+    throw new Error('Kolom tanggal tidak ditemukan pada data yang ditempel');
+  }
+  if (layout.amounts.length === 0) {
+    throw new Error('Kolom jumlah tidak ditemukan pada data yang ditempel');
+  }
+
+  const { dateCol, descCol, amounts } = layout;
+  const transactions: TransactionItem[] = [];
+  rows.forEach((row, i) => {
+    const tanggal = parsePastedDate(row[dateCol] || '');
+    if (!tanggal) return;
+
+    const penerimaan: Record<string, number> = {};
+    const pengeluaran: Record<string, number> = {};
+    for (const column of amounts) {
+      const amount = parsePastedAmount(row[column.index] || '');
+      if (!amount) continue;
+      // A signed column picks the type by sign; a fixed-type column keeps the
+      // sign so refunds and reversals stay negative
+      const type = column.type ?? (amount > 0 ? 'penerimaan' : 'pengeluaran');
+      const target = type === 'penerimaan' ? penerimaan : pengeluaran;
+      target[column.category] = (target[column.category] || 0) + (column.type ? amount : Math.abs(amount));
+    }
+    if (Object.keys(penerimaan).length === 0 && Object.keys(pengeluaran).length === 0) return;
+
+    transactions.push({
+      id: `tx-${Date.now()}-${i}-${Math.random().toString(36).substr(2, 4)}`,
+      tanggal,
+      uraian: (descCol >= 0 ? row[descCol] : '') || 'Transaksi Tanpa Keterangan',
+      penerimaan,
+      pengeluaran,
+      jumlah: getNetAmount({ penerimaan, pengeluaran }),
+    });
+  });
+
+  if (transactions.length === 0) {
+    throw new Error('Tidak ada transaksi yang valid pada data yang ditempel');
+  }
+  return { transactions, columns: describeColumns(layout, width, headers), hasHeader: headers !== null };
+};
@@ -0,0 +1,51 @@
+import type { TransactionItem } from '@/types';
+
+/**
+ * Monthly spending limits per pengeluaran category.
+ *
+ * Limits apply to the category total across all accounts for the calendar
+ * month of the transaction.
+ */
+export type SpendingLimits = Record<string, number>;
+
+export interface SpendingLimitWarning {
+  category: string;
+  month: string; // YYYY-MM
+  limit: number;
+  total: number;
+}
+
+const monthOf = (tanggal: string): string => (tanggal || '').slice(0, 7);
+
+// Month-to-date pengeluaran for a category, up to and including the given date
+export const getMonthToDateSpending = (
+  transactions: TransactionItem[],
+  category: string,
+  tanggal: string
+): number => {
+  const month = monthOf(tanggal);
+  const day = (tanggal || '').slice(0, 10);
+  return transactions.reduce((sum, tx) => {
+    const txDay = (tx.tanggal || '').slice(0, 10);
+    if (monthOf(txDay) !== month || txDay > day) return sum;
+    return sum + (Number(tx.pengeluaran?.[category]) || 0);
+  }, 0);
+};
+
+// Limits crossed by the pengeluaran categories of a transaction being saved.
+// `transactions` must already include the transaction; a category that was
+// over its limit before this amount is not reported again.
+export const checkSpendingLimits = (
+  transaction: Pick<TransactionItem, 'tanggal' | 'pengeluaran'>,
+  transactions: TransactionItem[],
+  limits: SpendingLimits
+): SpendingLimitWarning[] =>
+  Object.entries(transaction.pengeluaran || {}).flatMap(([category, amount]) => {
+    const limit = limits[category];
+    if (!(limit > 0)) return [];
+    const total = getMonthToDateSpending(transactions, category, transaction.tanggal);
+    const before = total - (Number(amount) || 0);
+    return before <= limit && limit < total
+      ? [{ category, month: monthOf(transaction.tanggal), limit, total }]
+      : [];
+  });
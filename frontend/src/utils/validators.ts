@@ -1,4 +1,5 @@
 import { TransactionItem } from '@/types';
+import { getNetAmount } from '@/utils/balance';
 
 // Date Validation
 export const isValidDate = (dateString: string): boolean => {
@@ -118,10 +119,7 @@ export const validateAccountBalance = (
   let calculatedBalance = initialBalance;
   
   for (const tx of transactions) {
-    const penerimaan = Object.values(tx.penerimaan || {}).reduce((sum, val) => sum + (Number(val) || 0), 0);
-    const pengeluaran = Object.values(tx.pengeluaran || {}).reduce((sum, val) => sum + (Number(val) || 0), 0);
-    const jumlah = penerimaan - pengeluaran;
-    calculatedBalance += jumlah;
+    calculatedBalance += getNetAmount(tx);
     
     // Check if balance would go negative
     if (calculatedBalance < 0) {
@@ -5,6 +5,8 @@ from typing import Dict, List, Optional
 from openpyxl.styles import PatternFill, Font, Border, Side
 from openpyxl.utils import get_column_letter
 
+from balances import net_amount
+
 def create_template(output_path: str = "template.xlsx", 
                    accounts: Optional[Dict[str, List[Dict]]] = None) -> str:
     """
@@ -66,9 +68,7 @@ def create_template(output_path: str = "template.xlsx",
         for account_name, transactions in accounts_data.items():
             running_balance = 0
             for tx in transactions:
-                income = sum(tx["Penerimaan"].values())
-                expense = sum(tx["Pengeluaran"].values())
-                running_balance += income - expense
+                running_balance += net_amount(tx["Penerimaan"], tx["Pengeluaran"])
                 tx["Jumlah"] = running_balance
     else:
         accounts_data = accounts
@@ -0,0 +1,165 @@
+"""
+Business health score and insights computed from the posted account data.
+
+Each heuristic looks at calendar months up to the latest month with
+transactions (or ``as_of``) and returns an insight with the figures behind
+it, so the insights panel can show why a message appears. There are no
+customers or invoices in the books, so payment behaviour is not covered.
+"""
+
+from datetime import date
+from typing import Any, Dict, Iterable, List, Optional
+
+from balances import net_amount, net_from_totals, sum_categories
+from formatting import format_rupiah
+from reports import dated_transactions, month_label, parse_filter_date, select_accounts
+
+# Points taken off the health score per insight severity
+SEVERITY_PENALTY = {"warning": 20, "info": 5, "positive": 0}
+
+# Thresholds for the heuristics
+TREND_THRESHOLD = 0.10  # Revenue change vs the previous 3 months
+SPIKE_RATIO = 1.5  # Category spend vs its 3-month average
+CONCENTRATION_SHARE = 0.5  # Share of income from one category
+
+
+def _month_keys(last: date, count: int) -> List[str]:
+    """The ``count`` YYYY-MM keys ending with the month of ``last``, oldest first."""
+    keys = []
+    year, month = last.year, last.month
+    for _ in range(count):
+        keys.append(f"{year:04d}-{month:02d}")
+        year, month = (year - 1, 12) if month == 1 else (year, month - 1)
+    return list(reversed(keys))
+
+
+def _insight(key: str, severity: str, title: str, message: str, **figures: Any) -> Dict[str, Any]:
+    return {"key": key, "severity": severity, "title": title, "message": message, "figures": figures}
+
+
+def _percent(value: float) -> str:
+    return f"{value * 100:.0f}%"
+
+
+def get_insights(
+    accounts: Iterable[Any], account_name: Optional[str] = None, as_of: Optional[str] = None
+) -> Dict[str, Any]:
+    """
+    Compute a health score (0-100) and ranked insights.
+
+    Heuristics:
+        revenue_trend: Income of the last 3 months vs the 3 months before
+        expense_spike: A category's spend in the latest month vs its 3-month average
+        concentration: Share of the last 12 months' income from one category
+        cash_runway: Months the balance lasts at the recent average monthly deficit
+
+    Raises:
+        KeyError: If ``account_name`` is given but no account has that name
+        ValueError: If ``as_of`` is not a valid date
+    """
+    rows = dated_transactions(select_accounts(accounts, account_name))
+    last = parse_filter_date(as_of, "as-of date") or (rows[-1][0] if rows else None)
+    if last is None:
+        return {"score": None, "as_of": None, "insights": []}
+
+    income: Dict[str, float] = {}
+    expense: Dict[str, float] = {}
+    expense_by_category: Dict[str, Dict[str, float]] = {}
+    income_by_category: Dict[str, Dict[str, float]] = {}
+    balance = 0.0
+    for tx_date, _, tx in rows:
+        if tx_date > last:
+            continue
+        month = tx_date.strftime("%Y-%m")
+        balance += net_amount(tx.penerimaan, tx.pengeluaran)
+        income[month] = income.get(month, 0.0) + sum_categories(tx.penerimaan)
+        expense[month] = expense.get(month, 0.0) + sum_categories(tx.pengeluaran)
+        for target, amounts in ((income_by_category, tx.penerimaan), (expense_by_category, tx.pengeluaran)):
+            for category, amount in (amounts or {}).items():
+                per_month = target.setdefault(category, {})
+                per_month[month] = per_month.get(month, 0.0) + float(amount or 0)
+
+    months = _month_keys(last, 12)
+    latest = months[-1]
+    recent, previous = months[-3:], months[-6:-3]
+    insights = []
+
+    recent_income = sum(income.get(m, 0.0) for m in recent)
+    previous_income = sum(income.get(m, 0.0) for m in previous)
+    if previous_income > 0:
+        change = (recent_income - previous_income) / previous_income
+        if abs(change) >= TREND_THRESHOLD:
+            rising = change > 0
+            insights.append(
+                _insight(
+                    "revenue_trend",
+                    "positive" if rising else "warning",
+                    "Penerimaan naik" if rising else "Penerimaan turun",
+                    f"Penerimaan 3 bulan terakhir {'naik' if rising else 'turun'} "
+                    f"{_percent(abs(change))} dibanding 3 bulan sebelumnya.",
+                    recent=recent_income,
+                    previous=previous_income,
+                    change_percent=change * 100,
+                )
+            )
+
+    for category, per_month in sorted(expense_by_category.items()):
+        current = per_month.get(latest, 0.0)
+        average = sum(per_month.get(m, 0.0) for m in months[-4:-1]) / 3
+        if average > 0 and current >= average * SPIKE_RATIO:
+            insights.append(
+                _insight(
+                    "expense_spike",
+                    "warning",
+                    f"Lonjakan pengeluaran {category}",
+                    f"Pengeluaran {category} pada {month_label(latest)} "
+                    f"{current / average:.1f}x rata-rata 3 bulan sebelumnya.",
+                    category=category,
+                    month=latest,
+                    amount=current,
+                    average=average,
+                )
+            )
+
+    year_income = sum(income.get(m, 0.0) for m in months)
+    if year_income > 0 and len(income_by_category) > 1:
+        totals = {
+            category: sum(per_month.get(m, 0.0) for m in months)
+            for category, per_month in income_by_category.items()
+        }
+        top, top_amount = max(totals.items(), key=lambda item: item[1])
+        share = top_amount / year_income
+        if share >= CONCENTRATION_SHARE:
+            insights.append(
+                _insight(
+                    "concentration",
+                    "warning" if share >= 0.75 else "info",
+                    "Penerimaan terpusat",
+                    f"{_percent(share)} penerimaan 12 bulan terakhir berasal dari {top}.",
+                    category=top,
+                    amount=top_amount,
+                    share_percent=share * 100,
+                )
+            )
+
+    average_net = sum(net_from_totals(income.get(m, 0.0), expense.get(m, 0.0)) for m in recent) / 3
+    if average_net < 0:
+        runway = balance / -average_net if balance > 0 else 0.0
+        insights.append(
+            _insight(
+                "cash_runway",
+                "warning" if runway < 6 else "info",
+                "Saldo terus berkurang",
+                f"Rata-rata defisit 3 bulan terakhir {format_rupiah(-average_net)} per bulan; "
+                + (f"saldo cukup untuk sekitar {runway:.1f} bulan." if balance > 0 else "saldo sudah habis."),
+                average_net=average_net,
+                balance=balance,
+                months=runway,
+            )
+        )
+
+    # Warnings first, then info, then positive (sort is stable within a severity)
+    order = list(SEVERITY_PENALTY)
+    insights.sort(key=lambda item: order.index(item["severity"]))
+    score = max(0, 100 - sum(SEVERITY_PENALTY[item["severity"]] for item in insights))
+    return {"score": score, "as_of": last.isoformat(), "insights": insights}
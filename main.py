@@ -8,15 +8,23 @@ from typing import List, Dict, Optional
 
 # Third-party imports
 import pandas as pd
-from fastapi import FastAPI, HTTPException, Request, Response
+from fastapi import FastAPI, File, HTTPException, Request, Response, UploadFile
 from fastapi.middleware.cors import CORSMiddleware
-from fastapi.responses import JSONResponse
+from fastapi.responses import HTMLResponse, JSONResponse
 from openpyxl.styles import PatternFill, Font, Border, Side
 from openpyxl.utils import get_column_letter
 from pydantic import BaseModel
 
 # Local imports
+import backups
+import charts
+import exports
 import generate_template
+import insights
+import report_html
+import reports
+import statements
+from balances import NORMAL_SIGN, PRESENTATIONS, SPLIT, net_amount
 
 # Set locale to Indonesian for month names
 locale.setlocale(locale.LC_TIME, "id_ID.UTF-8")
@@ -131,6 +139,126 @@ class TemplateData(BaseModel):
     accounts: List[AccountData]
 
 
+class ReportParams(BaseModel):
+    """Parameters that select the data a report covers."""
+
+    account: Optional[str] = None  # All accounts when empty
+    start_date: Optional[str] = None  # Inclusive, YYYY-MM-DD
+    end_date: Optional[str] = None  # Inclusive, YYYY-MM-DD
+    units: Optional[str] = None  # Round to "unit" or "thousands"; unrounded when empty
+    presentation: Optional[str] = None  # "split" (default) or "signed" amount columns
+
+    class Config:
+        alias_generator = to_camel_case
+        allow_population_by_field_name = True
+
+
+class ReportRequest(BaseModel):
+    """Account data plus the report to generate from it."""
+
+    report: str = "summary"
+    accounts: List[AccountData]
+    params: ReportParams = ReportParams()
+
+
+class CompareRequest(BaseModel):
+    """Two parameter sets to run the same report for and compare."""
+
+    report: str = "summary"
+    accounts: List[AccountData]
+    params_a: ReportParams = ReportParams()
+    params_b: ReportParams = ReportParams()
+    # Optional second data set (e.g. an edited copy); defaults to accounts
+    accounts_b: Optional[List[AccountData]] = None
+
+    class Config:
+        alias_generator = to_camel_case
+        allow_population_by_field_name = True
+
+
+class ExportFilter(ReportParams):
+    """Transaction filter for export bundles, mirroring the editor filters."""
+
+    entry_type: Optional[str] = None  # "penerimaan" or "pengeluaran"
+    search: Optional[str] = None  # Matches description or category name
+
+
+class ExportRequest(BaseModel):
+    """Account data plus the filter selecting what to export."""
+
+    accounts: List[AccountData]
+    filter: ExportFilter = ExportFilter()
+
+
+class PackageRequest(BaseModel):
+    """Account data plus the account and period to cover (report data, statement package, charts)."""
+
+    accounts: List[AccountData]
+    params: ReportParams = ReportParams()
+
+
+class PeriodPackageRequest(BaseModel):
+    """Account data plus the fiscal period to hand to the accountant."""
+
+    accounts: List[AccountData]
+    period: str  # YYYY-MM or YYYY
+    account: Optional[str] = None  # All accounts when empty
+    presentation: Optional[str] = None  # "split" (default) or "signed" amount columns
+
+
+class InsightsRequest(BaseModel):
+    """Account data plus the account and date the insights are computed for."""
+
+    accounts: List[AccountData]
+    account: Optional[str] = None  # All accounts when empty
+    as_of: Optional[str] = None  # YYYY-MM-DD; latest transaction date when empty
+
+    class Config:
+        alias_generator = to_camel_case
+        allow_population_by_field_name = True
+
+
+def _check_display_options(params: BaseModel) -> None:
+    """Reject display units or an amount presentation the exports do not know."""
+    units = getattr(params, "units", None)
+    if units and units not in reports.DISPLAY_UNITS:
+        raise HTTPException(
+            status_code=400,
+            detail=f"Unsupported display units: {units}. "
+            f"Expected one of: {', '.join(reports.DISPLAY_UNITS)}",
+        )
+    presentation = getattr(params, "presentation", None)
+    if presentation and presentation not in PRESENTATIONS:
+        raise HTTPException(
+            status_code=400,
+            detail=f"Unsupported presentation: {presentation}. "
+            f"Expected one of: {', '.join(PRESENTATIONS)}",
+        )
+
+
+def _build_report(accounts: List[AccountData], params: ReportParams) -> Dict:
+    """
+    Build report data rounded to the requested units.
+
+    An invalid date filter becomes a 400 and an unknown account a 404.
+    """
+    _check_display_options(params)
+    try:
+        report = reports.build_report(
+            accounts,
+            account_name=params.account,
+            start_date=params.start_date,
+            end_date=params.end_date,
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+    except KeyError:
+        raise HTTPException(
+            status_code=404, detail=f"Account not found: {params.account}"
+        )
+    return reports.round_report(report, params.units)
+
+
 # API Endpoints
 @app.get("/")
 async def root():
@@ -317,6 +445,227 @@ async def save_file(data: TemplateData, request: Request):
                 print(f"Error cleaning up temp file: {e}")
 
 
+@app.post("/api/reports/html", response_class=HTMLResponse)
+async def render_report_html(data: ReportRequest) -> HTMLResponse:
+    """
+    Render a report as print-ready, self-contained HTML.
+
+    The page has its styles inlined and page-break hints for A4, so it can be
+    printed from a new browser window without the interactive report layout.
+    """
+    if data.report not in report_html.REPORT_TYPES:
+        raise HTTPException(
+            status_code=400,
+            detail=f"Unsupported report type: {data.report}. "
+            f"Expected one of: {', '.join(report_html.REPORT_TYPES)}",
+        )
+
+    report = _build_report(data.accounts, data.params)
+    html = report_html.render_report_html(
+        data.report, report, presentation=data.params.presentation or SPLIT
+    )
+    return HTMLResponse(content=html)
+
+
+@app.post("/api/reports/data")
+async def get_report_data(data: PackageRequest):
+    """
+    Return the report figures and every chart series for the account and
+    period as JSON.
+
+    Amounts follow ``params.units``, so the reports page shows the same
+    rounded totals as the printed and exported documents. The chart series
+    come from the same report, so the page needs one request per change.
+    """
+    report = _build_report(data.accounts, data.params)
+    return {**report, "charts": charts.all_series(report)}
+
+
+@app.post("/api/reports/compare")
+async def compare_reports(data: CompareRequest):
+    """
+    Run a report for two parameter sets and return aligned rows with deltas.
+
+    Deltas are B - A, so comparing last month (A) with this month (B) shows
+    growth as a positive number. Both sides must be rounded to the same
+    units, otherwise the deltas would mix rounded and unrounded figures.
+    """
+    if data.report not in reports.COMPARABLE_REPORTS:
+        raise HTTPException(
+            status_code=400,
+            detail=f"Unsupported report type: {data.report}. "
+            f"Expected one of: {', '.join(reports.COMPARABLE_REPORTS)}",
+        )
+    if (data.params_a.units or None) != (data.params_b.units or None):
+        raise HTTPException(
+            status_code=400,
+            detail="params_a and params_b must use the same units "
+            f"(got {data.params_a.units or 'none'} and {data.params_b.units or 'none'})",
+        )
+
+    report_a = _build_report(data.accounts, data.params_a)
+    accounts_b = data.accounts_b if data.accounts_b is not None else data.accounts
+    report_b = _build_report(accounts_b, data.params_b)
+    return reports.compare_reports(data.report, report_a, report_b)
+
+
+@app.post("/api/export/working-paper")
+async def export_working_paper(data: ExportRequest) -> Response:
+    """
+    Export the transactions matching a filter as a zip of CSV files.
+
+    Meant for handing a specific selection (account, period, category) to an
+    auditor without sharing the whole workbook.
+    """
+    params = data.filter
+    if params.entry_type and params.entry_type not in NORMAL_SIGN:
+        raise HTTPException(
+            status_code=400, detail=f"Unsupported entry type: {params.entry_type}"
+        )
+    _check_display_options(params)
+
+    try:
+        content = exports.build_working_paper(
+            data.accounts,
+            account_name=params.account,
+            start_date=params.start_date,
+            end_date=params.end_date,
+            entry_type=params.entry_type,
+            search=params.search,
+            units=params.units,
+            presentation=params.presentation or SPLIT,
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+    except KeyError:
+        raise HTTPException(
+            status_code=404, detail=f"Account not found: {params.account}"
+        )
+
+    filename = f"kertas_kerja_{datetime.now().strftime('%Y%m%d_%H%M%S')}.zip"
+    return Response(
+        content=content,
+        media_type="application/zip",
+        headers={"Content-Disposition": f'attachment; filename="{filename}"'},
+    )
+
+
+@app.post("/api/export/financial-package")
+async def export_financial_package(data: PackageRequest) -> Response:
+    """
+    Export the year-end statement package as one multi-sheet workbook.
+
+    All sheets use the same account selection and period, so the totals agree
+    between the income statement, cash flow and monthly sheets.
+    """
+    params = data.params
+    _check_display_options(params)
+    try:
+        content = statements.build_statement_package(
+            data.accounts,
+            account_name=params.account,
+            start_date=params.start_date,
+            end_date=params.end_date,
+            units=params.units,
+            presentation=params.presentation or SPLIT,
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+    except KeyError:
+        raise HTTPException(
+            status_code=404, detail=f"Account not found: {params.account}"
+        )
+
+    filename = f"paket_laporan_{datetime.now().strftime('%Y%m%d_%H%M%S')}.xlsx"
+    return Response(
+        content=content,
+        media_type="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        headers={"Content-Disposition": f'attachment; filename="{filename}"'},
+    )
+
+
+@app.post("/api/backups/compare")
+async def compare_backups(
+    file_a: UploadFile = File(...), file_b: UploadFile = File(...)
+):
+    """
+    Compare two exported workbooks and list what changed from A to B.
+
+    A is the older backup and B the newer one, so "added" transactions are
+    the ones that only exist in B.
+    """
+    try:
+        backup_a = backups.read_backup(await file_a.read())
+        backup_b = backups.read_backup(await file_b.read())
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+    return backups.compare_backups(backup_a, backup_b)
+
+
+@app.post("/api/export/period-package")
+async def export_period_package(data: PeriodPackageRequest) -> Response:
+    """
+    Export one month or year as a zip of CSV files for the accountant's closing.
+    """
+    _check_display_options(data)
+    try:
+        content = exports.build_period_package(
+            data.accounts,
+            data.period,
+            account_name=data.account,
+            presentation=data.presentation or SPLIT,
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+    except KeyError:
+        raise HTTPException(
+            status_code=404, detail=f"Account not found: {data.account}"
+        )
+
+    filename = f"paket_periode_{data.period.strip()}.zip"
+    return Response(
+        content=content,
+        media_type="application/zip",
+        headers={"Content-Disposition": f'attachment; filename="{filename}"'},
+    )
+
+
+@app.post("/api/insights")
+async def get_insights(data: InsightsRequest):
+    """
+    Compute the business health score and ranked insights for the dashboard.
+
+    Each insight carries the figures it is based on, so the panel can show
+    them next to the message.
+    """
+    try:
+        return insights.get_insights(data.accounts, data.account, data.as_of)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e))
+    except KeyError:
+        raise HTTPException(
+            status_code=404, detail=f"Account not found: {data.account}"
+        )
+
+
+@app.post("/api/charts/{chart}")
+async def get_chart_series(chart: str, data: PackageRequest):
+    """
+    Return one pre-aggregated chart series for the account and period.
+
+    Amounts follow ``params.units``, like the other report endpoints.
+    """
+    if chart not in charts.CHARTS:
+        raise HTTPException(
+            status_code=400,
+            detail=f"Unknown chart: {chart}. Expected one of: {', '.join(charts.CHARTS)}",
+        )
+
+    report = _build_report(data.accounts, data.params)
+    return {"chart": chart, "series": charts.CHARTS[chart](report)}
+
+
 # Helper functions
 def _create_excel_file(data: TemplateData, output_path: str) -> None:
     """
@@ -369,22 +718,8 @@ def _create_excel_file(data: TemplateData, output_path: str) -> None:
                 running_balance = 0.0
 
                 for tx in account.transactions or []:
-                    # Calculate total penerimaan and pengeluaran for this transaction
-                    total_penerimaan = (
-                        sum((tx.penerimaan or {}).values())
-                        if hasattr(tx, "penerimaan")
-                        else 0.0
-                    )
-                    total_pengeluaran = (
-                        sum((tx.pengeluaran or {}).values())
-                        if hasattr(tx, "pengeluaran")
-                        else 0.0
-                    )
-
                     # Calculate running balance
-                    running_balance = (
-                        running_balance + total_penerimaan - total_pengeluaran
-                    )
+                    running_balance += net_amount(tx.penerimaan, tx.pengeluaran)
 
                     # Format the date as DD Month YYYY (e.g., 24 September 2024)
                     tanggal = getattr(tx, "tanggal", "")
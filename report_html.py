@@ -0,0 +1,211 @@
+"""
+Print-ready HTML rendering of reports.
+
+The output is a single self-contained HTML document (styles included, no
+scripts or external assets) laid out for A4 printing, so printing does not
+depend on the interactive reports page.
+"""
+
+from datetime import datetime
+from html import escape
+from string import Template
+from typing import Any, Dict, List, Optional, Tuple
+
+from balances import SIGNED, SPLIT
+from formatting import format_rupiah
+
+# Report types accepted by render_report_html
+REPORT_TYPES = {
+    "summary": "Laporan Keuangan",
+    "monthly": "Laporan Bulanan",
+    "yearly": "Laporan Tahunan",
+    "running": "Laporan Saldo Berjalan",
+    "categories": "Laporan per Kategori",
+}
+
+PAGE_TEMPLATE = Template(
+    """<!DOCTYPE html>
+<html lang="id">
+<head>
+<meta charset="utf-8">
+<title>$title</title>
+<style>
+  @page { size: A4; margin: 15mm; }
+  body { font-family: Arial, Helvetica, sans-serif; font-size: 10pt; color: #111827; margin: 0; }
+  h1 { font-size: 16pt; margin: 0 0 4pt 0; }
+  h2 { font-size: 12pt; margin: 16pt 0 6pt 0; border-bottom: 1px solid #d1d5db; padding-bottom: 3pt; }
+  .meta { color: #4b5563; font-size: 9pt; margin-bottom: 12pt; }
+  .meta div { margin: 1pt 0; }
+  section.break { page-break-before: always; }
+  table { width: 100%; border-collapse: collapse; margin-bottom: 8pt; }
+  thead { display: table-header-group; }
+  tr { page-break-inside: avoid; }
+  th, td { border: 1px solid #d1d5db; padding: 3pt 5pt; text-align: left; }
+  th { background: #f3f4f6; font-weight: bold; }
+  td.num, th.num { text-align: right; white-space: nowrap; }
+  tfoot td { font-weight: bold; background: #f9fafb; }
+  .income { color: #047857; }
+  .expense { color: #b91c1c; }
+  .empty { color: #6b7280; font-style: italic; }
+  .footer { margin-top: 16pt; color: #6b7280; font-size: 8pt; }
+</style>
+</head>
+<body>
+<h1>$title</h1>
+<div class="meta">
+$meta
+</div>
+$sections
+<div class="footer">Dibuat pada $generated_at</div>
+</body>
+</html>
+"""
+)
+
+
+def _table(
+    headers: List[Tuple[str, bool]], rows: List[List[str]], footer: Optional[List[str]] = None
+) -> str:
+    """Render a table; each header is (label, is_numeric) and cells are pre-escaped HTML."""
+    if not rows:
+        return '<p class="empty">Tidak ada transaksi pada periode ini.</p>'
+
+    def cells(values: List[str], tag: str) -> str:
+        return "".join(
+            f'<{tag} class="num">{value}</{tag}>' if numeric else f"<{tag}>{value}</{tag}>"
+            for value, (_, numeric) in zip(values, headers)
+        )
+
+    head = cells([escape(label) for label, _ in headers], "th")
+    body = "\n".join(f"<tr>{cells(row, 'td')}</tr>" for row in rows)
+    foot = f"<tfoot><tr>{cells(footer, 'td')}</tr></tfoot>" if footer else ""
+    return f"<table><thead><tr>{head}</tr></thead><tbody>\n{body}\n</tbody>{foot}</table>"
+
+
+def _period_section(title: str, periods: List[Dict[str, Any]], totals: Dict[str, float]) -> str:
+    headers = [("Periode", False), ("Penerimaan", True), ("Pengeluaran", True), ("Selisih", True)]
+    rows = [
+        [
+            escape(period["label"]),
+            format_rupiah(period["income"]),
+            format_rupiah(period["expense"]),
+            format_rupiah(period["net"]),
+        ]
+        for period in periods
+    ]
+    footer = [
+        "Total",
+        format_rupiah(totals["income"]),
+        format_rupiah(totals["expense"]),
+        format_rupiah(totals["net"]),
+    ]
+    return f"<h2>{escape(title)}</h2>\n{_table(headers, rows, footer)}"
+
+
+def _running_section(report: Dict[str, Any], presentation: str = SPLIT) -> str:
+    show_account = len(report["accounts"]) > 1
+    signed = presentation == SIGNED
+    headers = [("Tanggal", False)]
+    if show_account:
+        headers.append(("Akun", False))
+    headers.append(("Uraian", False))
+    headers += [("Jumlah", True)] if signed else [("Penerimaan", True), ("Pengeluaran", True)]
+    headers.append(("Saldo Berjalan", True))
+
+    rows = []
+    if report["start_date"]:
+        opening = [escape(report["start_date"])] + ([""] if show_account else [])
+        amounts = [""] if signed else ["", ""]
+        rows.append(opening + ["Saldo Awal"] + amounts + [format_rupiah(report["opening_balance"])])
+    for row in report["running"]:
+        cells = [escape(row["date"])] + ([escape(row["account"])] if show_account else [])
+        if signed:
+            amounts = [format_rupiah(row["net"])]
+        else:
+            amounts = [
+                format_rupiah(row["income"]) if row["income"] else "",
+                format_rupiah(row["expense"]) if row["expense"] else "",
+            ]
+        rows.append(
+            cells + [escape(row["uraian"])] + amounts + [format_rupiah(row["running_balance"])]
+        )
+    return f"<h2>Saldo Berjalan</h2>\n{_table(headers, rows)}"
+
+
+def _category_section(report: Dict[str, Any]) -> str:
+    parts = []
+    for key, label, css in (
+        ("income_by_category", "Penerimaan", "income"),
+        ("expense_by_category", "Pengeluaran", "expense"),
+    ):
+        categories = report[key]
+        rows = [
+            [escape(name), f'<span class="{css}">{format_rupiah(amount)}</span>']
+            for name, amount in categories.items()
+        ]
+        footer = ["Total", format_rupiah(sum(categories.values()))]
+        table = _table([("Kategori", False), (label, True)], rows, footer)
+        parts.append(f"<h2>{label} per Kategori</h2>\n{table}")
+    return "\n".join(parts)
+
+
+def render_report_html(
+    report_type: str,
+    report: Dict[str, Any],
+    generated_at: Optional[datetime] = None,
+    presentation: str = SPLIT,
+) -> str:
+    """
+    Render report data from ``reports.build_report`` as print-ready HTML.
+
+    Args:
+        report_type: One of ``REPORT_TYPES``
+        report: Report data to render
+        generated_at: Timestamp shown in the footer (defaults to now)
+        presentation: Split or signed amount columns in the running balance
+
+    Raises:
+        ValueError: If ``report_type`` is not supported
+    """
+    if report_type not in REPORT_TYPES:
+        raise ValueError(f"Unsupported report type: {report_type}")
+
+    generated_at = generated_at or datetime.now()
+    title = REPORT_TYPES[report_type]
+
+    period = "Semua periode"
+    if report["start_date"] or report["end_date"]:
+        period = f"{report['start_date'] or 'awal'} s/d {report['end_date'] or 'akhir'}"
+    meta_lines = [
+        f"<div>Akun: {escape(', '.join(report['accounts']) or '-')}</div>",
+        f"<div>Periode: {escape(period)}</div>",
+        f"<div>Saldo awal: {format_rupiah(report['opening_balance'])}"
+        f" &middot; Saldo akhir: {format_rupiah(report['closing_balance'])}</div>",
+    ]
+    if report.get("figures_in_thousands"):
+        meta_lines.append("<div>Angka dalam ribuan Rupiah</div>")
+    meta = "\n".join(meta_lines)
+
+    builders = {
+        "monthly": lambda: _period_section("Ringkasan Bulanan", report["monthly"], report["totals"]),
+        "yearly": lambda: _period_section("Ringkasan Tahunan", report["yearly"], report["totals"]),
+        "running": lambda: _running_section(report, presentation),
+        "categories": lambda: _category_section(report),
+    }
+    if report_type == "summary":
+        order = ["categories", "monthly", "yearly", "running"]
+    else:
+        order = [report_type]
+
+    # Start every section after the first on a new printed page
+    sections = "\n".join(
+        f'<section class="{"break" if index else ""}">{builders[name]()}</section>'
+        for index, name in enumerate(order)
+    )
+
+    return PAGE_TEMPLATE.substitute(
+        title=escape(title),
+        meta=meta,
+        sections=sections,
+        generated_at=escape(generated_at.strftime("%Y-%m-%d %H:%M")),
+    )
@@ -0,0 +1,333 @@
+"""
+Report computations shared by the report endpoints.
+
+Reports are built from the account data posted by the frontend, so the
+backend stays stateless. All amounts go through the sign rules in
+``balances``.
+"""
+
+from datetime import date, datetime
+from decimal import ROUND_HALF_UP, Decimal
+from typing import Any, Dict, Iterable, List, Optional, Sequence, Tuple
+
+from balances import net_amount, net_from_totals, sum_categories
+
+# Short Indonesian month names, matching the reports page
+MONTH_NAMES = [
+    "Jan", "Feb", "Mar", "Apr", "Mei", "Jun",
+    "Jul", "Agu", "Sep", "Okt", "Nov", "Des",
+]
+
+
+def parse_date(value: Any) -> Optional[date]:
+    """Parse a transaction date (YYYY-MM-DD, optionally followed by a time)."""
+    if isinstance(value, datetime):
+        return value.date()
+    if isinstance(value, date):
+        return value
+    if not value:
+        return None
+    try:
+        return datetime.strptime(str(value).strip()[:10], "%Y-%m-%d").date()
+    except ValueError:
+        return None
+
+
+def parse_filter_date(value: Optional[str], field: str = "date") -> Optional[date]:
+    """
+    Parse a report filter date, where empty means "no limit".
+
+    Raises:
+        ValueError: If ``value`` is given but is not a valid YYYY-MM-DD date
+    """
+    if not value or not str(value).strip():
+        return None
+    parsed = parse_date(value)
+    if parsed is None:
+        raise ValueError(f"Invalid {field}: {value}. Expected YYYY-MM-DD")
+    return parsed
+
+
+def month_label(period: str) -> str:
+    """Format a YYYY-MM period key as e.g. 'Jan 2025'."""
+    year, month = period.split("-")
+    return f"{MONTH_NAMES[int(month) - 1]} {year}"
+
+
+def period_range(period: str) -> Tuple[date, date]:
+    """
+    Return the first and last day of a YYYY-MM month or YYYY year.
+
+    Raises:
+        ValueError: If ``period`` is not in either format
+    """
+    text = (period or "").strip()
+    try:
+        if len(text) == 4:
+            year = int(text)
+            return date(year, 1, 1), date(year, 12, 31)
+        start = datetime.strptime(text, "%Y-%m").date()
+    except ValueError:
+        raise ValueError(f"Invalid period: {period}. Expected YYYY-MM or YYYY")
+    next_month = date(start.year + start.month // 12, start.month % 12 + 1, 1)
+    return start, date.fromordinal(next_month.toordinal() - 1)
+
+
+def select_accounts(accounts: Iterable[Any], account_name: Optional[str] = None) -> List[Any]:
+    """
+    Return the accounts a report should cover.
+
+    Raises:
+        KeyError: If ``account_name`` is given but no account has that name
+    """
+    accounts = list(accounts)
+    if not account_name:
+        return accounts
+    selected = [account for account in accounts if account.name == account_name]
+    if not selected:
+        raise KeyError(account_name)
+    return selected
+
+
+def dated_transactions(accounts: Iterable[Any]) -> List[Tuple[date, Any, Any]]:
+    """
+    Collect (date, account, transaction) tuples sorted by date.
+
+    Transactions without a valid date are left out, since they cannot be
+    placed in a period. The sort is stable, so same-day entries keep the order
+    they were entered in.
+    """
+    rows = []
+    for account in accounts:
+        for tx in account.transactions or []:
+            tx_date = parse_date(tx.tanggal)
+            if tx_date is not None:
+                rows.append((tx_date, account, tx))
+    rows.sort(key=lambda row: row[0])
+    return rows
+
+
+def _empty_totals() -> Dict[str, float]:
+    return {"income": 0.0, "expense": 0.0, "net": 0.0}
+
+
+def _add_totals(totals: Dict[str, float], income: float, expense: float) -> None:
+    totals["income"] += income
+    totals["expense"] += expense
+    totals["net"] = net_from_totals(totals["income"], totals["expense"])
+
+
+def build_report(
+    accounts: Iterable[Any],
+    account_name: Optional[str] = None,
+    start_date: Optional[str] = None,
+    end_date: Optional[str] = None,
+) -> Dict[str, Any]:
+    """
+    Build the report data for one account, or all accounts combined.
+
+    The running balance starts from the opening balance, i.e. the net of all
+    transactions before ``start_date``, so it matches the account balance
+    rather than restarting at zero for a filtered period.
+
+    Args:
+        accounts: Account objects with ``name`` and ``transactions``
+        account_name: Limit the report to this account
+        start_date: Inclusive start date (YYYY-MM-DD)
+        end_date: Inclusive end date (YYYY-MM-DD)
+
+    Returns:
+        Dict with totals, monthly, yearly and running balance rows, and
+        totals per income and expense category
+
+    Raises:
+        KeyError: If ``account_name`` is given but no account has that name
+        ValueError: If ``start_date`` or ``end_date`` is not a valid date
+    """
+    selected = select_accounts(accounts, account_name)
+    start = parse_filter_date(start_date, "start date")
+    end = parse_filter_date(end_date, "end date")
+
+    opening_balance = 0.0
+    totals = _empty_totals()
+    monthly: Dict[str, Dict[str, float]] = {}
+    yearly: Dict[str, Dict[str, float]] = {}
+    running = []
+    income_by_category: Dict[str, float] = {}
+    expense_by_category: Dict[str, float] = {}
+
+    running_balance = 0.0
+    for tx_date, account, tx in dated_transactions(selected):
+        net = net_amount(tx.penerimaan, tx.pengeluaran)
+        if start and tx_date < start:
+            opening_balance += net
+            running_balance += net
+            continue
+        if end and tx_date > end:
+            continue
+
+        income = sum_categories(tx.penerimaan)
+        expense = sum_categories(tx.pengeluaran)
+        running_balance += net
+
+        _add_totals(totals, income, expense)
+        _add_totals(monthly.setdefault(tx_date.strftime("%Y-%m"), _empty_totals()), income, expense)
+        _add_totals(yearly.setdefault(str(tx_date.year), _empty_totals()), income, expense)
+
+        for category, amount in (tx.penerimaan or {}).items():
+            income_by_category[category] = income_by_category.get(category, 0.0) + float(amount or 0)
+        for category, amount in (tx.pengeluaran or {}).items():
+            expense_by_category[category] = expense_by_category.get(category, 0.0) + float(amount or 0)
+
+        running.append(
+            {
+                "date": tx_date.isoformat(),
+                "account": account.name,
+                "uraian": tx.uraian,
+                "income": income,
+                "expense": expense,
+                "net": net,
+                "running_balance": running_balance,
+            }
+        )
+
+    return {
+        "accounts": [account.name for account in selected],
+        "start_date": start.isoformat() if start else None,
+        "end_date": end.isoformat() if end else None,
+        "opening_balance": opening_balance,
+        "closing_balance": running_balance,
+        "totals": totals,
+        "monthly": [
+            {"period": period, "label": month_label(period), **values}
+            for period, values in sorted(monthly.items())
+        ],
+        "yearly": [
+            {"period": period, "label": period, **values}
+            for period, values in sorted(yearly.items())
+        ],
+        "running": running,
+        "income_by_category": dict(sorted(income_by_category.items())),
+        "expense_by_category": dict(sorted(expense_by_category.items())),
+        "units": None,
+        "figures_in_thousands": False,
+    }
+
+
+# Display units for rounded reports, with the divisor applied before rounding
+DISPLAY_UNITS = {"unit": 1, "thousands": 1000}
+
+
+def round_amount(amount: float, units: Optional[str] = None) -> float:
+    """
+    Round an amount to whole units or thousands, half away from zero.
+
+    Returns the amount unchanged when ``units`` is empty.
+    """
+    if not units:
+        return amount
+    scaled = Decimal(str(amount)) / DISPLAY_UNITS[units]
+    return float(scaled.quantize(Decimal("1"), rounding=ROUND_HALF_UP))
+
+
+def round_report(report: Dict[str, Any], units: Optional[str] = None) -> Dict[str, Any]:
+    """
+    Return a copy of ``build_report`` data with every amount rounded.
+
+    Each figure is rounded on its own, as printed statements do, so a rounded
+    total can differ by a unit from the sum of its rounded lines.
+
+    Raises:
+        ValueError: If ``units`` is not in ``DISPLAY_UNITS``
+    """
+    if not units:
+        return report
+    if units not in DISPLAY_UNITS:
+        raise ValueError(f"Unsupported display units: {units}")
+
+    def amounts(values: Dict[str, Any], keys: Iterable[str]) -> Dict[str, Any]:
+        return {**values, **{key: round_amount(values[key], units) for key in keys}}
+
+    period_keys = ("income", "expense", "net")
+    return {
+        **report,
+        "opening_balance": round_amount(report["opening_balance"], units),
+        "closing_balance": round_amount(report["closing_balance"], units),
+        "totals": amounts(report["totals"], period_keys),
+        "monthly": [amounts(row, period_keys) for row in report["monthly"]],
+        "yearly": [amounts(row, period_keys) for row in report["yearly"]],
+        "running": [amounts(row, period_keys + ("running_balance",)) for row in report["running"]],
+        "income_by_category": amounts(report["income_by_category"], report["income_by_category"]),
+        "expense_by_category": amounts(report["expense_by_category"], report["expense_by_category"]),
+        "units": units,
+        "figures_in_thousands": units == "thousands",
+    }
+
+
+def round_rows(rows: Iterable[Sequence[Any]], units: Optional[str] = None) -> List[List[Any]]:
+    """Round the amount (float) cells of table rows, leaving other cells as they are."""
+    return [
+        [round_amount(value, units) if isinstance(value, float) else value for value in row]
+        for row in rows
+    ]
+
+
+# Report types that compare_reports can align
+COMPARABLE_REPORTS = ("summary", "categories")
+
+
+def _delta_row(key: str, label: str, a: float, b: float, **extra: Any) -> Dict[str, Any]:
+    delta = b - a
+    return {
+        "key": key,
+        "label": label,
+        **extra,
+        "a": a,
+        "b": b,
+        "delta": delta,
+        # Percent change relative to A; undefined when A is zero
+        "delta_percent": (delta / abs(a) * 100) if a else None,
+    }
+
+
+def compare_reports(report_type: str, report_a: Dict[str, Any], report_b: Dict[str, Any]) -> Dict[str, Any]:
+    """
+    Align two reports built by ``build_report`` and compute B - A per row.
+
+    ``summary`` compares balances and totals; ``categories`` compares every
+    income and expense category present in either report (missing ones count
+    as 0).
+
+    Raises:
+        ValueError: If ``report_type`` is not in ``COMPARABLE_REPORTS``
+    """
+    if report_type not in COMPARABLE_REPORTS:
+        raise ValueError(f"Unsupported report type: {report_type}")
+
+    if report_type == "summary":
+        rows = [
+            _delta_row("opening_balance", "Saldo Awal", report_a["opening_balance"], report_b["opening_balance"]),
+            _delta_row("income", "Penerimaan", report_a["totals"]["income"], report_b["totals"]["income"]),
+            _delta_row("expense", "Pengeluaran", report_a["totals"]["expense"], report_b["totals"]["expense"]),
+            _delta_row("net", "Selisih", report_a["totals"]["net"], report_b["totals"]["net"]),
+            _delta_row("closing_balance", "Saldo Akhir", report_a["closing_balance"], report_b["closing_balance"]),
+        ]
+    else:
+        rows = []
+        for entry_type, key in (("penerimaan", "income_by_category"), ("pengeluaran", "expense_by_category")):
+            categories_a, categories_b = report_a[key], report_b[key]
+            for name in sorted(set(categories_a) | set(categories_b)):
+                rows.append(
+                    _delta_row(
+                        f"{entry_type}:{name}",
+                        name,
+                        categories_a.get(name, 0.0),
+                        categories_b.get(name, 0.0),
+                        type=entry_type,
+                    )
+                )
+
+    def describe(report: Dict[str, Any]) -> Dict[str, Any]:
+        return {key: report[key] for key in ("accounts", "start_date", "end_date")}
+
+    return {"report": report_type, "a": describe(report_a), "b": describe(report_b), "rows": rows}
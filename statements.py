@@ -0,0 +1,235 @@
+"""
+Year-end financial statement package as a single multi-sheet workbook.
+
+The app keeps single-entry cash books, so the package covers the statements
+that follow from them: a cash-basis income statement, cash flow per account,
+monthly totals, the transaction journal and notes data. There is no balance
+sheet, equity statement or trial balance, since accounts carry no assets,
+liabilities or equity.
+
+Every sheet starts with the same header (title, accounts, period), and all
+figures come from ``reports.build_report`` for the same period, so they agree
+across sheets and with the reports page.
+"""
+
+import io
+from datetime import datetime
+from typing import Any, Dict, Iterable, List, Optional, Sequence, Tuple
+
+from openpyxl import Workbook
+from openpyxl.styles import Border, Font, PatternFill, Side
+from openpyxl.utils import get_column_letter
+
+from balances import SPLIT
+from exports import filter_transactions, journal_headers, journal_rows
+from reports import build_report, round_report, round_rows, select_accounts
+
+NUMBER_FORMAT = "#,##0_);(#,##0)"
+
+HEADER_FILL = PatternFill(start_color="F3F4F6", end_color="F3F4F6", fill_type="solid")
+THIN_BORDER = Border(
+    left=Side(style="thin"),
+    right=Side(style="thin"),
+    top=Side(style="thin"),
+    bottom=Side(style="thin"),
+)
+
+
+def period_label(start_date: Optional[str], end_date: Optional[str]) -> str:
+    """Describe a period the same way the printed reports do."""
+    if not start_date and not end_date:
+        return "Semua periode"
+    return f"{start_date or 'awal'} s/d {end_date or 'akhir'}"
+
+
+def _write_sheet(
+    wb: Workbook,
+    title: str,
+    heading: Sequence[str],
+    headers: Sequence[str],
+    rows: Iterable[Sequence[Any]],
+    total_rows: Sequence[int] = (),
+) -> None:
+    """
+    Add a sheet with the package header followed by one table.
+
+    ``total_rows`` are indexes into ``rows`` that are shown in bold.
+    """
+    ws = wb.create_sheet(title=title[:31])
+    for line_number, line in enumerate(heading, 1):
+        cell = ws.cell(row=line_number, column=1, value=line)
+        if line_number == 1:
+            cell.font = Font(bold=True, size=14)
+
+    header_row = len(heading) + 2
+    for col, header in enumerate(headers, 1):
+        cell = ws.cell(row=header_row, column=col, value=header)
+        cell.font = Font(bold=True)
+        cell.fill = HEADER_FILL
+        cell.border = THIN_BORDER
+
+    for index, row in enumerate(rows):
+        for col, value in enumerate(row, 1):
+            cell = ws.cell(row=header_row + 1 + index, column=col, value=value)
+            cell.border = THIN_BORDER
+            if isinstance(value, (int, float)):
+                cell.number_format = NUMBER_FORMAT
+            if index in total_rows:
+                cell.font = Font(bold=True)
+
+    for col, header in enumerate(headers, 1):
+        width = 40 if header in ("Uraian", "Keterangan", "Kategori") else 18
+        ws.column_dimensions[get_column_letter(col)].width = width
+    ws.freeze_panes = f"A{header_row + 1}"
+
+
+def _income_statement_rows(report: Dict[str, Any]) -> Tuple[List[List[Any]], List[int]]:
+    """Income statement lines and the indexes of its subtotal lines."""
+    rows: List[List[Any]] = []
+    totals = []
+    for label, key, total_key in (
+        ("Penerimaan", "income_by_category", "income"),
+        ("Pengeluaran", "expense_by_category", "expense"),
+    ):
+        rows.append([label, ""])
+        totals.append(len(rows) - 1)
+        rows += [[f"  {name}", amount] for name, amount in report[key].items()]
+        rows.append([f"Total {label.lower()}", report["totals"][total_key]])
+        totals.append(len(rows) - 1)
+        rows.append(["", ""])
+    net = report["totals"]["net"]
+    rows.append(["Surplus" if net >= 0 else "Defisit", net])
+    totals.append(len(rows) - 1)
+    return rows, totals
+
+
+def build_statement_package(
+    accounts: Iterable[Any],
+    account_name: Optional[str] = None,
+    start_date: Optional[str] = None,
+    end_date: Optional[str] = None,
+    units: Optional[str] = None,
+    presentation: str = SPLIT,
+    generated_at: Optional[datetime] = None,
+) -> bytes:
+    """
+    Build the statement package workbook for one account or all accounts.
+
+    ``units`` rounds every figure (see ``reports.round_report``); with
+    "thousands" each sheet header says the figures are in thousands.
+    ``presentation`` selects split or signed amounts in the journal sheet.
+
+    Sheets:
+        Ringkasan: Balances and totals for the period
+        Laba Rugi: Income and expense per category (cash basis)
+        Arus Kas: Opening balance, receipts, payments and closing balance per account
+        Bulanan: Totals per month
+        Transaksi: Journal lines, one per category amount
+        Catatan: Data for the notes (counts, categories, basis of preparation)
+
+    Raises:
+        KeyError: If ``account_name`` is given but no account has that name
+        ValueError: If ``start_date`` or ``end_date`` is not a valid date
+    """
+    generated_at = generated_at or datetime.now()
+    accounts = list(accounts)
+    selected = select_accounts(accounts, account_name)
+    report = round_report(build_report(selected, None, start_date, end_date), units)
+    per_account = [
+        round_report(build_report([account], None, start_date, end_date), units)
+        for account in selected
+    ]
+    transactions = filter_transactions(selected, None, start_date, end_date)
+
+    period = period_label(report["start_date"], report["end_date"])
+    account_label = account_name or "Semua akun"
+
+    def heading(title: str) -> List[str]:
+        lines = [title, f"Akun: {account_label}", f"Periode: {period}"]
+        if report["figures_in_thousands"]:
+            lines.append("Angka dalam ribuan Rupiah")
+        return lines
+
+    wb = Workbook()
+    wb.remove(wb.active)
+
+    totals = report["totals"]
+    _write_sheet(
+        wb,
+        "Ringkasan",
+        heading("Ringkasan Laporan Keuangan"),
+        ["Keterangan", "Nilai"],
+        [
+            ["Saldo awal", report["opening_balance"]],
+            ["Total penerimaan", totals["income"]],
+            ["Total pengeluaran", totals["expense"]],
+            ["Selisih", totals["net"]],
+            ["Saldo akhir", report["closing_balance"]],
+        ],
+        total_rows=(4,),
+    )
+
+    income_rows, income_totals = _income_statement_rows(report)
+    _write_sheet(
+        wb,
+        "Laba Rugi",
+        heading("Laporan Laba Rugi (Basis Kas)"),
+        ["Kategori", "Jumlah"],
+        income_rows,
+        total_rows=income_totals,
+    )
+
+    cash_rows = [
+        [
+            account_report["accounts"][0],
+            account_report["opening_balance"],
+            account_report["totals"]["income"],
+            account_report["totals"]["expense"],
+            account_report["closing_balance"],
+        ]
+        for account_report in per_account
+    ]
+    cash_rows.append(
+        ["Total", report["opening_balance"], totals["income"], totals["expense"], report["closing_balance"]]
+    )
+    _write_sheet(
+        wb,
+        "Arus Kas",
+        heading("Laporan Arus Kas"),
+        ["Akun", "Saldo Awal", "Penerimaan", "Pengeluaran", "Saldo Akhir"],
+        cash_rows,
+        total_rows=(len(cash_rows) - 1,),
+    )
+
+    monthly_rows = [
+        [month["label"], month["income"], month["expense"], month["net"]]
+        for month in report["monthly"]
+    ]
+    monthly_rows.append(["Total", totals["income"], totals["expense"], totals["net"]])
+    _write_sheet(
+        wb,
+        "Bulanan",
+        heading("Ringkasan Bulanan"),
+        ["Periode", "Penerimaan", "Pengeluaran", "Selisih"],
+        monthly_rows,
+        total_rows=(len(monthly_rows) - 1,),
+    )
+
+    journal = round_rows(journal_rows(transactions, presentation), units)
+    _write_sheet(
+        wb, "Transaksi", heading("Jurnal Transaksi"), journal_headers(presentation), journal
+    )
+
+    notes = [
+        ["Dasar penyusunan", "Basis kas, dari pencatatan penerimaan dan pengeluaran per akun"],
+        ["Jumlah akun", len(selected)],
+        ["Jumlah transaksi", len(transactions)],
+        ["Kategori penerimaan", ", ".join(report["income_by_category"]) or "-"],
+        ["Kategori pengeluaran", ", ".join(report["expense_by_category"]) or "-"],
+        ["Dibuat pada", generated_at.strftime("%Y-%m-%d %H:%M:%S")],
+    ]
+    _write_sheet(wb, "Catatan", heading("Catatan atas Laporan Keuangan"), ["Keterangan", "Nilai"], notes)
+
+    buffer = io.BytesIO()
+    wb.save(buffer)
+    return buffer.getvalue()
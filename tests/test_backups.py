@@ -0,0 +1,73 @@
+import unittest
+
+from backups import compare_backups, parse_backup_date
+
+
+def tx(tanggal, uraian, penerimaan=None, pengeluaran=None):
+    return {"tanggal": tanggal, "uraian": uraian, "penerimaan": penerimaan or {}, "pengeluaran": pengeluaran or {}}
+
+
+class ParseBackupDateTest(unittest.TestCase):
+    def test_indonesian_month_names(self):
+        self.assertEqual(parse_backup_date("15 Mei 2025"), "2025-05-15")
+        self.assertEqual(parse_backup_date("2025-05-15 00:00:00"), "2025-05-15")
+        self.assertEqual(parse_backup_date("kemarin"), "kemarin")
+
+
+class CompareBackupsTest(unittest.TestCase):
+    def setUp(self):
+        self.backup_a = {
+            "Kas": [
+                tx("2025-01-05", "Penjualan", {"Penjualan": 1000.0}),
+                tx("2025-01-06", "Gaji", pengeluaran={"Gaji": 300.0}),
+                tx("2025-01-07", "Sewa", pengeluaran={"Sewa": 200.0}),
+            ],
+            "Lama": [tx("2025-01-01", "Setoran", {"Modal": 50.0})],
+        }
+        self.backup_b = {
+            "Kas": [
+                tx("2025-01-05", "Penjualan", {"Penjualan": 1000.0}),
+                tx("2025-01-06", "Gaji", pengeluaran={"Gaji": 350.0}),
+                tx("2025-01-08", "Listrik", pengeluaran={"Listrik": 100.0}),
+            ],
+            "Bank": [
+                tx("2025-01-02", "Setoran", {"Modal": 500.0}),
+                tx("2025-01-03", "Biaya admin", pengeluaran={"Admin": 10.0}),
+            ],
+        }
+
+    def test_changed_account_lists_added_removed_and_edited(self):
+        result = compare_backups(self.backup_a, self.backup_b)
+        [kas] = result["accounts_changed"]
+        self.assertEqual(kas["name"], "Kas")
+        self.assertEqual([t["uraian"] for t in kas["added"]], ["Listrik"])
+        self.assertEqual([t["uraian"] for t in kas["removed"]], ["Sewa"])
+        self.assertEqual(kas["changed"][0]["after"]["pengeluaran"], {"Gaji": 350.0})
+        self.assertEqual(kas["balance_delta"], 50.0)
+
+    def test_accounts_only_in_one_backup(self):
+        result = compare_backups(self.backup_a, self.backup_b)
+        self.assertEqual(result["accounts_added"], [{"name": "Bank", "balance": 490.0, "transactions": 2}])
+        self.assertEqual(result["accounts_removed"], [{"name": "Lama", "balance": 50.0, "transactions": 1}])
+
+    def test_summary_counts_transactions_of_added_and_removed_accounts(self):
+        summary = compare_backups(self.backup_a, self.backup_b)["summary"]
+        self.assertEqual(summary["transactions_added"], 1 + 2)
+        self.assertEqual(summary["transactions_removed"], 1 + 1)
+        self.assertEqual(summary["transactions_changed"], 1)
+        self.assertEqual(summary["balance_a"], 550.0)
+        self.assertEqual(summary["balance_b"], 1040.0)
+
+    def test_identical_backups_have_no_changes(self):
+        result = compare_backups(self.backup_a, self.backup_a)
+        self.assertEqual(result["accounts_changed"], [])
+        self.assertEqual(result["summary"]["transactions_added"], 0)
+
+    def test_duplicate_transactions_are_matched_one_to_one(self):
+        sale = tx("2025-01-05", "Penjualan", {"Penjualan": 1000.0})
+        result = compare_backups({"Kas": [sale]}, {"Kas": [sale, dict(sale)]})
+        self.assertEqual(len(result["accounts_changed"][0]["added"]), 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,51 @@
+import unittest
+
+from balances import (
+    PENERIMAAN,
+    PENGELUARAN,
+    net_amount,
+    net_from_totals,
+    presentation_amount,
+    sum_categories,
+)
+
+
+class SumCategoriesTest(unittest.TestCase):
+    def test_missing_values_count_as_zero(self):
+        self.assertEqual(sum_categories({"Gaji": 100.0, "Sewa": None}), 100.0)
+        self.assertEqual(sum_categories(None), 0.0)
+
+
+class NetTest(unittest.TestCase):
+    def test_income_adds_and_expense_subtracts(self):
+        self.assertEqual(net_amount({"Penjualan": 1000.0}, {"Gaji": 300.0, "Sewa": 200.0}), 500.0)
+
+    def test_net_from_totals_matches_net_amount(self):
+        self.assertEqual(net_from_totals(1000.0, 700.0), net_amount({"A": 1000.0}, {"B": 700.0}))
+        self.assertEqual(net_from_totals(None, 50.0), -50.0)
+
+    def test_negative_entry_reverses_its_effect(self):
+        # A refund booked as a negative expense raises the balance
+        self.assertEqual(net_amount({}, {"Pembelian": -250.0}), 250.0)
+
+
+class PresentationAmountTest(unittest.TestCase):
+    def test_sign_follows_the_effect_on_the_balance(self):
+        self.assertEqual(presentation_amount(PENERIMAAN, 1000.0), 1000.0)
+        self.assertEqual(presentation_amount(PENGELUARAN, 400.0), -400.0)
+
+    def test_refund_flips_sign(self):
+        self.assertEqual(presentation_amount(PENGELUARAN, -250.0), 250.0)
+        self.assertEqual(presentation_amount(PENERIMAAN, -100.0), -100.0)
+
+    def test_signed_lines_add_up_to_the_net(self):
+        penerimaan = {"Penjualan": 1000.0, "Retur": -100.0}
+        pengeluaran = {"Gaji": 300.0, "Pembelian": -50.0}
+        lines = [presentation_amount(PENERIMAAN, v) for v in penerimaan.values()] + [
+            presentation_amount(PENGELUARAN, v) for v in pengeluaran.values()
+        ]
+        self.assertEqual(sum(lines), net_amount(penerimaan, pengeluaran))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,54 @@
+import unittest
+
+import charts
+
+
+def report(**overrides):
+    base = {
+        "start_date": None,
+        "opening_balance": 0.0,
+        "monthly": [{"period": "2025-01", "label": "Jan 2025", "income": 100.0, "expense": 40.0, "net": 60.0}],
+        "yearly": [{"period": "2025", "label": "2025", "income": 100.0, "expense": 40.0, "net": 60.0}],
+        "running": [
+            {"date": "2025-01-05", "running_balance": 100.0},
+            {"date": "2025-01-05", "running_balance": 80.0},
+            {"date": "2025-01-09", "running_balance": 60.0},
+        ],
+        "income_by_category": {"Penjualan": 100.0},
+        "expense_by_category": {"Gaji": 30.0, "Sewa": 10.0},
+    }
+    return {**base, **overrides}
+
+
+class ChartsTest(unittest.TestCase):
+    def test_balance_keeps_the_end_of_day_balance(self):
+        points = charts.balance_over_time(report(start_date="2025-01-01", opening_balance=20.0))
+        self.assertEqual(
+            points,
+            [
+                {"date": "2025-01-01", "balance": 20.0},
+                {"date": "2025-01-05", "balance": 80.0},
+                {"date": "2025-01-09", "balance": 60.0},
+            ],
+        )
+
+    def test_long_balance_history_is_thinned_to_the_closing_balance(self):
+        running = [{"date": f"day-{i:05d}", "running_balance": float(i)} for i in range(1000)]
+        points = charts.balance_over_time(report(running=running))
+        self.assertEqual(len(points), charts.MAX_BALANCE_POINTS)
+        self.assertEqual(points[-1]["balance"], 999.0)
+
+    def test_mix_groups_small_categories(self):
+        categories = {f"K{i}": float(10 - i) for i in range(8)}
+        mix = charts.expense_mix(report(expense_by_category=categories))
+        self.assertEqual(len(mix), charts.MIX_SLICES + 1)
+        self.assertEqual(mix[-1], {"name": "Lainnya", "value": 7.0, "share_percent": 7.0 / 52 * 100})
+
+    def test_all_series_cover_every_chart(self):
+        series = charts.all_series(report())
+        self.assertEqual(set(series), set(charts.CHARTS))
+        self.assertEqual(series["yearly"][0]["net"], 60.0)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,40 @@
+import unittest
+from types import SimpleNamespace
+
+from insights import get_insights
+
+
+def account(name, *transactions):
+    return SimpleNamespace(
+        name=name,
+        transactions=[
+            SimpleNamespace(tanggal=tanggal, uraian="", penerimaan=penerimaan, pengeluaran=pengeluaran)
+            for tanggal, penerimaan, pengeluaran in transactions
+        ],
+    )
+
+
+class InsightsTest(unittest.TestCase):
+    def test_no_transactions_has_no_score(self):
+        self.assertEqual(get_insights([account("Kas")]), {"score": None, "as_of": None, "insights": []})
+
+    def test_cash_runway_uses_the_average_monthly_deficit(self):
+        kas = account(
+            "Kas",
+            ("2025-01-01", {"Modal": 900.0}, {}),
+            ("2025-02-10", {}, {"Sewa": 100.0}),
+            ("2025-03-10", {}, {"Sewa": 100.0}),
+            ("2025-04-10", {}, {"Sewa": 100.0}),
+        )
+        result = get_insights([kas])
+        runway = next(i for i in result["insights"] if i["key"] == "cash_runway")
+        self.assertEqual(runway["figures"]["average_net"], -100.0)
+        self.assertIn("Rp100", runway["message"])
+
+    def test_invalid_as_of_date_raises(self):
+        with self.assertRaises(ValueError):
+            get_insights([account("Kas")], as_of="2025-02-30")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,98 @@
+import unittest
+from types import SimpleNamespace
+
+from reports import build_report, compare_reports, round_amount, round_report, round_rows
+
+
+def account(name, *transactions):
+    return SimpleNamespace(
+        name=name,
+        transactions=[
+            SimpleNamespace(tanggal=tanggal, uraian=uraian, penerimaan=penerimaan, pengeluaran=pengeluaran)
+            for tanggal, uraian, penerimaan, pengeluaran in transactions
+        ],
+    )
+
+
+KAS = account(
+    "Kas",
+    ("2025-01-05", "Penjualan", {"Penjualan": 1_250_500.0}, {}),
+    ("2025-01-20", "Gaji", {}, {"Gaji": 400_499.0}),
+    ("2025-02-03", "Sewa", {}, {"Sewa": 300_000.0}),
+)
+
+
+class BuildReportTest(unittest.TestCase):
+    def test_totals_and_balances(self):
+        report = build_report([KAS])
+        self.assertEqual(report["totals"], {"income": 1_250_500.0, "expense": 700_499.0, "net": 550_001.0})
+        self.assertEqual(report["closing_balance"], 550_001.0)
+        self.assertEqual([row["period"] for row in report["monthly"]], ["2025-01", "2025-02"])
+
+    def test_opening_balance_covers_transactions_before_the_start(self):
+        report = build_report([KAS], start_date="2025-02-01")
+        self.assertEqual(report["opening_balance"], 850_001.0)
+        self.assertEqual(report["closing_balance"], 550_001.0)
+        self.assertEqual(report["totals"]["expense"], 300_000.0)
+
+    def test_invalid_filter_date_raises(self):
+        with self.assertRaises(ValueError):
+            build_report([KAS], start_date="2025-13-45")
+
+    def test_unknown_account_raises(self):
+        with self.assertRaises(KeyError):
+            build_report([KAS], account_name="Bank")
+
+
+class RoundingTest(unittest.TestCase):
+    def test_round_amount_half_away_from_zero(self):
+        self.assertEqual(round_amount(1_250.5, "unit"), 1_251.0)
+        self.assertEqual(round_amount(-1_250.5, "unit"), -1_251.0)
+        self.assertEqual(round_amount(1_250_500.0, "thousands"), 1_251.0)
+        self.assertEqual(round_amount(1_250.5), 1_250.5)
+
+    def test_round_report_rounds_each_figure(self):
+        report = round_report(build_report([KAS]), "thousands")
+        self.assertEqual(report["totals"], {"income": 1_251.0, "expense": 700.0, "net": 550.0})
+        self.assertEqual(report["expense_by_category"]["Gaji"], 400.0)
+        self.assertEqual(report["running"][-1]["running_balance"], 550.0)
+        self.assertTrue(report["figures_in_thousands"])
+
+    def test_round_report_without_units_is_unchanged(self):
+        report = build_report([KAS])
+        self.assertIs(round_report(report, None), report)
+
+    def test_round_report_rejects_unknown_units(self):
+        with self.assertRaises(ValueError):
+            round_report(build_report([KAS]), "millions")
+
+    def test_round_rows_leaves_other_cells(self):
+        rows = round_rows([["2025-01-05", "Penjualan", 1_250.5, 3]], "unit")
+        self.assertEqual(rows, [["2025-01-05", "Penjualan", 1_251.0, 3]])
+
+
+class CompareReportsTest(unittest.TestCase):
+    def test_summary_deltas_are_b_minus_a(self):
+        january = build_report([KAS], end_date="2025-01-31")
+        february = build_report([KAS], start_date="2025-02-01")
+        rows = {row["key"]: row for row in compare_reports("summary", january, february)["rows"]}
+        self.assertEqual(rows["expense"]["delta"], 300_000.0 - 400_499.0)
+        self.assertEqual(rows["closing_balance"]["a"], 850_001.0)
+        self.assertEqual(rows["opening_balance"]["delta_percent"], None)
+
+    def test_categories_missing_on_one_side_count_as_zero(self):
+        january = build_report([KAS], end_date="2025-01-31")
+        february = build_report([KAS], start_date="2025-02-01")
+        rows = {row["key"]: row for row in compare_reports("categories", january, february)["rows"]}
+        self.assertEqual(rows["pengeluaran:Sewa"]["a"], 0.0)
+        self.assertEqual(rows["pengeluaran:Sewa"]["b"], 300_000.0)
+        self.assertEqual(rows["penerimaan:Penjualan"]["delta_percent"], -100.0)
+
+    def test_unknown_report_type_raises(self):
+        report = build_report([KAS])
+        with self.assertRaises(ValueError):
+            compare_reports("running", report, report)
+
+
+if __name__ == "__main__":
+    unittest.main()